@@ -2,8 +2,9 @@
 //!
 //! This module handles finding Cargo.toml files within a git repository,
 //! supporting both simple repositories and monorepos. It can search upwards
-//! from the current directory or perform a breadth-first search when needed,
-//! with user interaction to select a manifest when multiple are found.
+//! from the current directory and perform a bounded breadth-first search.
+//! Workspace member crates are collapsed into their workspace root, and the
+//! user is asked to select manifests when multiple independent ones are found.
 
 use std::collections::VecDeque;
 use std::ffi::OsStr;
@@ -11,7 +12,7 @@ use std::fs;
 use std::path::{Component, Path, PathBuf};
 
 use anyhow::{anyhow, Context, Result};
-use dialoguer::Select;
+use dialoguer::MultiSelect;
 
 use crate::util::relative_display;
 
@@ -21,58 +22,74 @@ pub struct ResolveHookOptions {
     pub non_interactive: bool,
 }
 
-pub fn resolve_cargo_manifest_dir(
-    maybe_manifest_dir_from_cli: Option<&Path>,
+pub fn resolve_cargo_manifest_dirs(
+    manifest_dirs_from_cli: &[PathBuf],
     cwd: &Path,
     repo_root: &Path,
     options: ResolveHookOptions,
-) -> Result<PathBuf> {
-    if let Some(manifest_dir) = maybe_manifest_dir_from_cli {
-        return resolve_manifest_dir_from_cli(repo_root, manifest_dir);
+) -> Result<Vec<PathBuf>> {
+    if !manifest_dirs_from_cli.is_empty() {
+        let mut manifest_dirs = manifest_dirs_from_cli
+            .iter()
+            .map(|manifest_dir| resolve_manifest_dir_from_cli(repo_root, manifest_dir))
+            .collect::<Result<Vec<_>>>()?;
+        manifest_dirs.sort();
+        manifest_dirs.dedup();
+        return Ok(manifest_dirs);
     }
 
+    // Monorepos may contain several independent workspaces, so we always do the bounded repo-wide
+    // scan in addition to walking upwards from cwd (which covers manifests deeper than the scan).
     let mut manifest_dirs = find_cargo_manifests_upwards(cwd, repo_root);
-    if manifest_dirs.is_empty() {
-        manifest_dirs = find_cargo_manifests_bfs(repo_root, 6, 8_000)?;
-    }
+    manifest_dirs.extend(find_cargo_manifests_bfs(repo_root, 6, 8_000)?);
+
+    // Member crates are formatted through their workspace root, so only offer independent
+    // workspaces (and standalone packages) as choices.
+    let mut manifest_dirs: Vec<PathBuf> = manifest_dirs
+        .iter()
+        .map(|dir| workspace_root_for_manifest_dir(repo_root, dir))
+        .collect();
 
     manifest_dirs.sort();
     manifest_dirs.dedup();
 
-    let Some(first_dir) = manifest_dirs.first() else {
+    if manifest_dirs.is_empty() {
         return Err(anyhow!(
             "No Cargo.toml found in git repository at {}",
             repo_root.display()
         ));
-    };
-
-    if manifest_dirs.len() == 1 {
-        return Ok(first_dir.clone());
     }
 
-    if options.non_interactive || options.yes {
-        return Err(anyhow!(
-            "Multiple Cargo.toml files found; re-run with --manifest-dir to choose one"
-        ));
+    if manifest_dirs.len() == 1 || options.non_interactive || options.yes {
+        return Ok(manifest_dirs);
     }
 
     let labels: Vec<String> = manifest_dirs
         .iter()
         .map(|dir| relative_display(repo_root, dir))
         .collect();
+    let defaults = vec![true; labels.len()];
 
-    let selected = Select::new()
-        .with_prompt("Multiple Cargo.toml files found. Which one should the hook use?")
-        .default(0)
+    let selected = MultiSelect::new()
+        .with_prompt("Multiple Cargo.toml files found. Which ones should the hook use?")
         .items(&labels)
+        .defaults(&defaults)
         .interact()
         .context("Failed to read selection from stdin")?;
 
-    let Some(selected_dir) = manifest_dirs.get(selected) else {
-        return Err(anyhow!("Invalid selection"));
-    };
+    if selected.is_empty() {
+        return Err(anyhow!("No Cargo.toml selected"));
+    }
 
-    Ok(selected_dir.clone())
+    let mut selected_dirs = Vec::with_capacity(selected.len());
+    for idx in selected {
+        let Some(selected_dir) = manifest_dirs.get(idx) else {
+            return Err(anyhow!("Invalid selection"));
+        };
+        selected_dirs.push(selected_dir.clone());
+    }
+
+    Ok(selected_dirs)
 }
 
 fn resolve_manifest_dir_from_cli(repo_root: &Path, manifest_dir: &Path) -> Result<PathBuf> {
@@ -122,6 +139,146 @@ fn ensure_is_within_repo(repo_root: &Path, candidate: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Returns the workspace root that owns the manifest in `manifest_dir`.
+///
+/// Mirrors Cargo's rules closely enough for hook purposes: an explicit `package.workspace` wins,
+/// otherwise the nearest ancestor (within the repo) with a `[workspace]` table owns the crate if
+/// its `members` globs match and its `exclude` list does not. Anything else is its own root.
+fn workspace_root_for_manifest_dir(repo_root: &Path, manifest_dir: &Path) -> PathBuf {
+    let Some(manifest) = read_cargo_manifest(manifest_dir) else {
+        return manifest_dir.to_path_buf();
+    };
+
+    if manifest.get("workspace").is_some_and(toml::Value::is_table) {
+        return manifest_dir.to_path_buf();
+    }
+
+    let maybe_workspace_path = manifest
+        .get("package")
+        .and_then(|package| package.get("workspace"))
+        .and_then(toml::Value::as_str);
+    if let Some(workspace_path) = maybe_workspace_path {
+        let workspace_root = normalize_lexically(&manifest_dir.join(workspace_path));
+        if workspace_root.join("Cargo.toml").is_file() {
+            return workspace_root;
+        }
+        return manifest_dir.to_path_buf();
+    }
+
+    for ancestor in manifest_dir.ancestors().skip(1) {
+        if !ancestor.starts_with(repo_root) {
+            break;
+        }
+
+        let Some(ancestor_manifest) = read_cargo_manifest(ancestor) else {
+            continue;
+        };
+        let Some(workspace) = ancestor_manifest
+            .get("workspace")
+            .and_then(toml::Value::as_table)
+        else {
+            continue;
+        };
+
+        // Cargo only considers the nearest enclosing workspace.
+        let Ok(rel) = manifest_dir.strip_prefix(ancestor) else {
+            break;
+        };
+        let rel = path_to_slash_string(rel);
+        let is_member = workspace_patterns(workspace, "members")
+            .iter()
+            .any(|pattern| glob_match_path(pattern, &rel));
+        let is_excluded = workspace_patterns(workspace, "exclude")
+            .iter()
+            .any(|pattern| rel == *pattern || rel.starts_with(&format!("{pattern}/")));
+        if is_member && !is_excluded {
+            return ancestor.to_path_buf();
+        }
+        break;
+    }
+
+    manifest_dir.to_path_buf()
+}
+
+fn read_cargo_manifest(dir: &Path) -> Option<toml::Table> {
+    let contents = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    contents.parse::<toml::Table>().ok()
+}
+
+fn workspace_patterns(workspace: &toml::Table, key: &str) -> Vec<String> {
+    let Some(values) = workspace.get(key).and_then(toml::Value::as_array) else {
+        return Vec::new();
+    };
+
+    values
+        .iter()
+        .filter_map(toml::Value::as_str)
+        .map(|pattern| {
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            pattern.to_string()
+        })
+        .collect()
+}
+
+fn path_to_slash_string(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Matches a slash-separated path against a Cargo `members` glob (`*`, `?` and `**`).
+fn glob_match_path(pattern: &str, path: &str) -> bool {
+    let pattern_parts: Vec<&str> = pattern.split('/').filter(|p| !p.is_empty()).collect();
+    let path_parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    glob_match_parts(&pattern_parts, &path_parts)
+}
+
+fn glob_match_parts(pattern: &[&str], path: &[&str]) -> bool {
+    let Some((first, rest)) = pattern.split_first() else {
+        return path.is_empty();
+    };
+
+    if *first == "**" {
+        return (0..=path.len()).any(|skip| glob_match_parts(rest, &path[skip..]));
+    }
+
+    let Some((path_first, path_rest)) = path.split_first() else {
+        return false;
+    };
+
+    glob_match_component(first.as_bytes(), path_first.as_bytes())
+        && glob_match_parts(rest, path_rest)
+}
+
+fn glob_match_component(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=text.len()).any(|skip| glob_match_component(rest, &text[skip..]))
+        }
+        Some((b'?', rest)) => !text.is_empty() && glob_match_component(rest, &text[1..]),
+        Some((ch, rest)) => text.first() == Some(ch) && glob_match_component(rest, &text[1..]),
+    }
+}
+
 pub fn find_cargo_manifests_upwards(cwd: &Path, repo_root: &Path) -> Vec<PathBuf> {
     let mut dirs = Vec::new();
     let mut current = cwd.to_path_buf();
@@ -228,4 +385,94 @@ mod tests {
         assert!(dirs.contains(&repo_root));
         Ok(())
     }
+
+    #[test]
+    fn resolve_cargo_manifest_dirs_keeps_all_independent_manifests_when_non_interactive(
+    ) -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let repo_root = temp.path().join("repo");
+        let service_a = repo_root.join("services").join("a");
+        let service_b = repo_root.join("services").join("b");
+        fs::create_dir_all(&service_a)?;
+        fs::create_dir_all(&service_b)?;
+        fs::write(service_a.join("Cargo.toml"), "[workspace]\n")?;
+        fs::write(service_b.join("Cargo.toml"), "[workspace]\n")?;
+
+        // act
+        let dirs = resolve_cargo_manifest_dirs(
+            &[],
+            &repo_root,
+            &repo_root,
+            ResolveHookOptions {
+                yes: true,
+                non_interactive: true,
+            },
+        )?;
+
+        // assert
+        assert_eq!(dirs, vec![service_a, service_b]);
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_cargo_manifest_dirs_collapses_workspace_members_into_root() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let repo_root = temp.path().join("repo");
+        let member_a = repo_root.join("crates").join("a");
+        let member_b = repo_root.join("crates").join("b");
+        let excluded = repo_root.join("crates").join("scratch");
+        fs::create_dir_all(&member_a)?;
+        fs::create_dir_all(&member_b)?;
+        fs::create_dir_all(&excluded)?;
+        fs::write(
+            repo_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/scratch\"]\n",
+        )?;
+        for dir in [&member_a, &member_b, &excluded] {
+            fs::write(
+                dir.join("Cargo.toml"),
+                "[package]\nname = \"x\"\nversion = \"0.1.0\"\n",
+            )?;
+        }
+
+        // act
+        let dirs = resolve_cargo_manifest_dirs(
+            &[],
+            &member_a,
+            &repo_root,
+            ResolveHookOptions {
+                yes: true,
+                non_interactive: true,
+            },
+        )?;
+
+        // assert
+        assert_eq!(dirs, vec![repo_root, excluded]);
+        Ok(())
+    }
+
+    #[test]
+    fn workspace_root_for_manifest_dir_follows_package_workspace_key() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let repo_root = temp.path().join("repo");
+        let workspace_root = repo_root.join("ws");
+        let member = repo_root.join("elsewhere").join("member");
+        fs::create_dir_all(&workspace_root)?;
+        fs::create_dir_all(&member)?;
+        fs::write(workspace_root.join("Cargo.toml"), "[workspace]\n")?;
+        fs::write(
+            member.join("Cargo.toml"),
+            "[package]\nname = \"member\"\nversion = \"0.1.0\"\nworkspace = \"../../ws\"\n",
+        )?;
+
+        // act
+        let root = workspace_root_for_manifest_dir(&repo_root, &member);
+
+        // assert
+        assert_eq!(root, workspace_root);
+        Ok(())
+    }
 }
@@ -4,7 +4,9 @@
 
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::hooks::HygieneChecks;
 
 #[derive(Debug, Parser)]
 #[command(name = "git-hook-installer", version, about)]
@@ -33,9 +35,9 @@ pub enum Command {
         #[arg(value_enum)]
         hook: Option<HookKind>,
 
-        /// Directory containing the Cargo.toml to use (only used for pre-commit)
-        #[arg(long, value_name = "DIR")]
-        manifest_dir: Option<PathBuf>,
+        /// Directory containing a Cargo.toml to use (only used for pre-commit; repeatable)
+        #[arg(long = "manifest-dir", value_name = "DIR")]
+        manifest_dirs: Vec<PathBuf>,
     },
     /// Install/update a hook across many git repos under a directory
     ///
@@ -46,9 +48,9 @@ pub enum Command {
         #[arg(value_enum)]
         hook: Option<HookKind>,
 
-        /// Directory containing the Cargo.toml to use (only used for pre-commit)
-        #[arg(long, value_name = "DIR")]
-        manifest_dir: Option<PathBuf>,
+        /// Directory containing a Cargo.toml to use (only used for pre-commit; repeatable)
+        #[arg(long = "manifest-dir", value_name = "DIR")]
+        manifest_dirs: Vec<PathBuf>,
 
         /// How deep to scan for git repositories (default: 1)
         ///
@@ -66,6 +68,17 @@ pub enum Command {
     Uninstall,
     /// List available premade hooks
     List,
+    /// Run a hook's built-in checks against the staged index
+    ///
+    /// The managed hook calls this when `git-hook-installer` is on PATH.
+    Run {
+        /// Hook whose checks to run
+        #[arg(value_enum)]
+        hook: HookKind,
+
+        #[command(flatten)]
+        hygiene: HygieneArgs,
+    },
     /// Inspect and report current hook state for this repository
     Status {
         /// Print more details (e.g. hook contents summary)
@@ -79,3 +92,43 @@ pub enum HookKind {
     /// pre-commit hook that runs common formatters/linters (managed block)
     PreCommit,
 }
+
+#[derive(Debug, Args)]
+pub struct HygieneArgs {
+    /// Reject staged files larger than this many KB (0 disables the limit)
+    #[arg(long, default_value_t = 500, value_name = "KB")]
+    pub max_file_kb: u32,
+
+    /// Don't reject staged merge conflict markers
+    #[arg(long)]
+    pub no_conflict_markers: bool,
+
+    /// Don't reject staged `.env` files and private keys
+    #[arg(long)]
+    pub no_sensitive_files: bool,
+
+    /// Don't fix mixed line endings
+    #[arg(long)]
+    pub no_line_endings: bool,
+
+    /// Don't add missing final newlines
+    #[arg(long)]
+    pub no_final_newline: bool,
+
+    /// Don't strip trailing whitespace
+    #[arg(long)]
+    pub no_trailing_whitespace: bool,
+}
+
+impl HygieneArgs {
+    pub fn checks(&self) -> HygieneChecks {
+        HygieneChecks {
+            conflict_markers_enabled: !self.no_conflict_markers,
+            maybe_max_file_size_kb: (self.max_file_kb > 0).then_some(self.max_file_kb),
+            sensitive_files_enabled: !self.no_sensitive_files,
+            line_endings_enabled: !self.no_line_endings,
+            final_newline_enabled: !self.no_final_newline,
+            trailing_whitespace_enabled: !self.no_trailing_whitespace,
+        }
+    }
+}
@@ -17,7 +17,11 @@ use anyhow::{anyhow, Context, Result};
 pub use fs::is_executable;
 pub use managed_block::MANAGED_BLOCK_BEGIN;
 pub use script::managed_pre_commit_block;
-pub use types::{InstallOptions, JavaKotlinTool, JsTsTool, ManagedPreCommitSettings, PythonTool};
+pub use types::{
+    CmakeTool, GoTool, HygieneChecks, InstallOptions, JavaKotlinTool, JsPackageManager, JsTsTool,
+    KubernetesTool, ManagedPreCommitSettings, MdYamlTool, PhpTool, PythonRunner, PythonTool,
+    PythonTypecheckTool, RubyTool, StageTimeout, StageTimeoutPolicy, SwiftTool, TerraformTool,
+};
 
 pub const PRE_COMMIT_HOOK_NAME: &str = "pre-commit";
 
@@ -110,15 +114,51 @@ mod tests {
         let settings = ManagedPreCommitSettings {
             enabled: true,
             maybe_js_ts_tool: Some(JsTsTool::Biome),
+            js_package_manager: JsPackageManager::Pnpm,
+            js_npx_fallback_enabled: false,
             ts_typecheck_enabled: true,
+            maybe_md_yaml_tool: Some(MdYamlTool::Prettier),
+            yamllint_enabled: true,
+            config_files_enabled: true,
             maybe_python_tool: Some(PythonTool::Ruff),
+            python_runner: PythonRunner::Uv,
+            maybe_python_typecheck_tool: Some(PythonTypecheckTool::Mypy),
             maybe_java_kotlin_tool: Some(JavaKotlinTool::Spotless),
-            go_enabled: true,
+            maybe_go_tool: Some(GoTool::GolangciLint),
+            go_vet_enabled: true,
             shell_enabled: true,
-            terraform_enabled: true,
+            maybe_terraform_tool: Some(TerraformTool::OpenTofu),
+            terraform_validate_enabled: true,
+            tflint_enabled: true,
             c_cpp_enabled: true,
-            ruby_enabled: true,
-            maybe_cargo_manifest_dir: None,
+            clang_tidy_enabled: true,
+            maybe_cmake_tool: Some(CmakeTool::Gersemi),
+            c_cpp_excluded_dirs: vec!["third_party".to_string(), "vendor".to_string()],
+            maybe_ruby_tool: Some(RubyTool::Standard),
+            ruby_unsafe_autocorrect_enabled: false,
+            maybe_swift_tool: Some(SwiftTool::SwiftFormat),
+            maybe_php_tool: Some(PhpTool::Pint),
+            csharp_enabled: true,
+            dart_enabled: true,
+            elixir_enabled: true,
+            zig_enabled: true,
+            dockerfile_lint_enabled: true,
+            github_actions_lint_enabled: true,
+            maybe_kubernetes_tool: Some(KubernetesTool::Kubeconform),
+            secrets_scan_enabled: true,
+            hygiene: HygieneChecks::default(),
+            native_runner_enabled: true,
+            cargo_manifest_dirs: Vec::new(),
+            parallel_stages_enabled: true,
+            timing_summary_enabled: true,
+            maybe_slow_stage_warn_seconds: None,
+            maybe_stage_timeout_seconds: None,
+            stage_timeout_policy: StageTimeoutPolicy::Fail,
+            stage_timeout_overrides: vec![StageTimeout {
+                stage: "js_ts",
+                seconds: 300,
+                policy: StageTimeoutPolicy::Skip,
+            }],
         };
         let repo_root = temp.path();
         let block = managed_pre_commit_block(&settings, repo_root);
@@ -137,6 +177,9 @@ mod tests {
         // assert
         let hook_path = git_dir.join("hooks").join(PRE_COMMIT_HOOK_NAME);
         assert!(hook_path.is_file());
+        let contents = std::fs::read_to_string(&hook_path)?;
+        assert!(contents.contains("GHI_TIMEOUT_JS_TS=300\nGHI_TIMEOUT_POLICY_JS_TS=\"skip\"\n"));
+        assert!(contents.contains("GHI_C_CPP_EXCLUDED_DIRS='third_party vendor'\n"));
         Ok(())
     }
 }
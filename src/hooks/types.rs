@@ -13,34 +13,321 @@ pub enum JsTsTool {
     PrettierEslint,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum MdYamlTool {
+    /// `prettier --write` on staged Markdown/YAML files.
+    Prettier,
+    /// `dprint fmt` on staged Markdown/YAML files (uses the repo's `dprint.json`).
+    Dprint,
+    /// `markdownlint-cli2 --fix` on staged Markdown files and `yamlfmt` on staged YAML files.
+    MarkdownlintYamlfmt,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TerraformTool {
+    Terraform,
+    /// `tofu`, a drop-in replacement for `terraform`.
+    OpenTofu,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RubyTool {
+    /// `rubocop -A`/`-a`.
+    Rubocop,
+    /// `standardrb --fix-unsafely`/`--fix` (the `standard` gem).
+    Standard,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CmakeTool {
+    /// `cmake-format -i` (cmakelang).
+    CmakeFormat,
+    /// `gersemi -i`.
+    Gersemi,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum KubernetesTool {
+    /// `kubeconform -strict` schema validation of staged manifests.
+    Kubeconform,
+    /// `kube-linter lint` best-practice checks of staged manifests.
+    KubeLinter,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsPackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum PythonTool {
     Ruff,
     Black,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum PythonTypecheckTool {
+    Mypy,
+    Pyright,
+}
+
+/// How the hook invokes Python tools (the project's environment vs. whatever is on PATH).
+#[derive(Debug, Clone, Copy)]
+pub enum PythonRunner {
+    Path,
+    Venv,
+    Uv,
+    Poetry,
+    Pipenv,
+    Hatch,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum JavaKotlinTool {
+    /// `spotlessApply` via `./gradlew` (or `gradle`).
     Spotless,
+    /// `spotless:apply` via `./mvnw` (or `mvn`), for POMs that configure the Spotless plugin.
+    SpotlessMaven,
+    /// `com.spotify.fmt:fmt-maven-plugin:format` via `./mvnw` (or `mvn`).
+    MavenFmt,
+    /// `google-java-format --replace` on staged `.java` files (and `ktlint -F` on Kotlin files).
+    GoogleJavaFormat,
+    /// `ktlint -F` on staged `.kt`/`.kts` files (and `google-java-format` on Java files).
     Ktlint,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum GoTool {
+    /// `goimports -w` (falls back to `gofmt -w` when goimports isn't installed).
+    Goimports,
+    /// goimports, then `golangci-lint run --fix --new-from-rev=HEAD` on the packages of staged files.
+    GolangciLint,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SwiftTool {
+    /// `swift-format format --in-place`.
+    SwiftFormat,
+    /// `swiftlint --fix`.
+    Swiftlint,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PhpTool {
+    /// `php-cs-fixer fix` (honors the repo's finder via `--path-mode=intersection`).
+    PhpCsFixer,
+    /// `phpcbf` from PHP_CodeSniffer.
+    Phpcbf,
+    /// Laravel Pint.
+    Pint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageTimeoutPolicy {
+    /// Abort the commit (and roll back) when the stage times out.
+    Fail,
+    /// Warn, restore the stage's files to their staged content, and continue.
+    Skip,
+}
+
+#[derive(Debug, Clone)]
+pub struct StageTimeout {
+    /// Stage name as used by the hook (e.g. `js_ts`, `java_kotlin`, `rust`).
+    pub stage: &'static str,
+    pub seconds: u32,
+    pub policy: StageTimeoutPolicy,
+}
+
+/// Language-independent checks that don't need external tools.
+#[derive(Debug, Clone, Copy)]
+pub struct HygieneChecks {
+    /// Reject staged lines that start with `<<<<<<<` or `>>>>>>>`.
+    pub conflict_markers_enabled: bool,
+    /// Reject staged files larger than this (in KB).
+    pub maybe_max_file_size_kb: Option<u32>,
+    /// Reject staged `.env` files and private keys.
+    pub sensitive_files_enabled: bool,
+    /// Normalize files that mix CRLF and LF to their majority line ending.
+    pub line_endings_enabled: bool,
+    /// Add a missing newline at the end of text files.
+    pub final_newline_enabled: bool,
+    /// Strip trailing spaces/tabs (except in Markdown, where they are line breaks, and in patch and
+    /// snapshot files, where they are content).
+    pub trailing_whitespace_enabled: bool,
+}
+
+impl HygieneChecks {
+    pub fn any_enabled(&self) -> bool {
+        self.conflict_markers_enabled
+            || self.maybe_max_file_size_kb.is_some()
+            || self.sensitive_files_enabled
+            || self.line_endings_enabled
+            || self.final_newline_enabled
+            || self.trailing_whitespace_enabled
+    }
+}
+
+impl Default for HygieneChecks {
+    fn default() -> Self {
+        Self {
+            conflict_markers_enabled: true,
+            maybe_max_file_size_kb: Some(500),
+            sensitive_files_enabled: true,
+            line_endings_enabled: true,
+            final_newline_enabled: true,
+            trailing_whitespace_enabled: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ManagedPreCommitSettings {
     pub enabled: bool,
-    /// If `None`, the hook will not attempt JS/TS (or Prettier-based Markdown/YAML) formatting.
+    /// If `None`, the hook will not attempt JS/TS formatting/linting.
     pub maybe_js_ts_tool: Option<JsTsTool>,
+    /// Used to run JS tools pinned by the repo when they aren't in `node_modules/.bin`.
+    pub js_package_manager: JsPackageManager,
+    /// If true, the hook may fall back to `npx --yes` (which can download unpinned versions).
+    pub js_npx_fallback_enabled: bool,
     /// If true, the hook will run a `tsc --noEmit` typecheck for TS repos.
     pub ts_typecheck_enabled: bool,
+    /// If `None`, the hook will not attempt Markdown/YAML formatting.
+    pub maybe_md_yaml_tool: Option<MdYamlTool>,
+    /// If true, the hook runs `yamllint` on staged YAML files after formatting them.
+    pub yamllint_enabled: bool,
+    /// If true, the hook formats staged TOML/JSON config files (taplo/dprint) and validates their syntax.
+    pub config_files_enabled: bool,
     /// If `None`, the hook will not attempt Python formatting/linting.
     pub maybe_python_tool: Option<PythonTool>,
+    /// Used to run Python tools from the project's environment when they aren't in `.venv/bin`.
+    pub python_runner: PythonRunner,
+    /// If set, the hook type-checks staged `.py` files after formatting them.
+    pub maybe_python_typecheck_tool: Option<PythonTypecheckTool>,
     /// If `None`, the hook will not attempt Java/Kotlin formatting/linting.
     pub maybe_java_kotlin_tool: Option<JavaKotlinTool>,
-    pub go_enabled: bool,
+    /// If `None`, the hook will not attempt Go formatting/linting.
+    pub maybe_go_tool: Option<GoTool>,
+    /// If true, the hook runs `go vet` on the packages that contain staged `.go` files.
+    pub go_vet_enabled: bool,
     pub shell_enabled: bool,
-    pub terraform_enabled: bool,
+    /// If `None`, the hook will not attempt Terraform/OpenTofu formatting.
+    pub maybe_terraform_tool: Option<TerraformTool>,
+    /// If true, the hook runs `terraform validate` in each module with staged files.
+    pub terraform_validate_enabled: bool,
+    /// If true, the hook runs `tflint` in each module with staged files.
+    pub tflint_enabled: bool,
     pub c_cpp_enabled: bool,
-    pub ruby_enabled: bool,
-    /// If set, `cargo fmt` will run from this directory.
-    pub maybe_cargo_manifest_dir: Option<PathBuf>,
+    /// If true, the hook runs `clang-tidy --fix` on staged C/C++ sources using `compile_commands.json`
+    /// (only while the repo has a `.clang-tidy`).
+    pub clang_tidy_enabled: bool,
+    /// If set (and C/C++ is enabled), the hook formats staged `CMakeLists.txt`/`*.cmake` files.
+    pub maybe_cmake_tool: Option<CmakeTool>,
+    /// Directory names (e.g. `third_party`) whose C/C++/CMake files the hook never touches.
+    pub c_cpp_excluded_dirs: Vec<String>,
+    /// If `None`, the hook will not attempt Ruby formatting/linting.
+    pub maybe_ruby_tool: Option<RubyTool>,
+    /// If true, the hook applies unsafe autocorrections (`rubocop -A`, `standardrb --fix-unsafely`).
+    pub ruby_unsafe_autocorrect_enabled: bool,
+    /// If `None`, the hook will not attempt Swift formatting/linting.
+    pub maybe_swift_tool: Option<SwiftTool>,
+    /// If `None`, the hook will not attempt PHP formatting/linting.
+    pub maybe_php_tool: Option<PhpTool>,
+    /// If true, the hook runs `dotnet format` on staged `.cs` files (per nearest `.sln`/`.csproj`).
+    pub csharp_enabled: bool,
+    pub dart_enabled: bool,
+    /// `mix format` runs from the nearest `mix.exs` directory of each staged Elixir file.
+    pub elixir_enabled: bool,
+    pub zig_enabled: bool,
+    /// If true, the hook runs `hadolint` on staged `Dockerfile*`/`Containerfile*` files.
+    pub dockerfile_lint_enabled: bool,
+    /// If true, the hook runs `actionlint` on staged `.github/workflows/*.yml` files.
+    pub github_actions_lint_enabled: bool,
+    /// If set, the hook checks staged manifests under `k8s/`/`kubernetes/` directories.
+    pub maybe_kubernetes_tool: Option<KubernetesTool>,
+    /// If true, the hook scans staged changes for secrets (gitleaks, trufflehog, or built-in patterns).
+    pub secrets_scan_enabled: bool,
+    pub hygiene: HygieneChecks,
+    /// If true, hygiene checks delegate to `git-hook-installer run pre-commit` when it's on PATH.
+    pub native_runner_enabled: bool,
+    /// `cargo fmt` runs once per directory that is the nearest manifest dir of a staged `.rs` file.
+    pub cargo_manifest_dirs: Vec<PathBuf>,
+    /// If true, language stages with disjoint file sets run concurrently in the hook.
+    pub parallel_stages_enabled: bool,
+    /// If true, the hook prints a per-stage timing summary (stage, file count, duration, result).
+    pub timing_summary_enabled: bool,
+    /// If set, the hook warns when a stage takes at least this many seconds.
+    pub maybe_slow_stage_warn_seconds: Option<u32>,
+    /// If set, every stage is killed after this many seconds (unless overridden per stage).
+    pub maybe_stage_timeout_seconds: Option<u32>,
+    pub stage_timeout_policy: StageTimeoutPolicy,
+    pub stage_timeout_overrides: Vec<StageTimeout>,
+}
+
+#[cfg(test)]
+impl HygieneChecks {
+    pub fn disabled() -> Self {
+        Self {
+            conflict_markers_enabled: false,
+            maybe_max_file_size_kb: None,
+            sensitive_files_enabled: false,
+            line_endings_enabled: false,
+            final_newline_enabled: false,
+            trailing_whitespace_enabled: false,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ManagedPreCommitSettings {
+    /// Every stage off (serial, no summary), so hook tests only enable what they exercise.
+    pub fn disabled() -> Self {
+        Self {
+            enabled: true,
+            maybe_js_ts_tool: None,
+            js_package_manager: JsPackageManager::Npm,
+            js_npx_fallback_enabled: false,
+            ts_typecheck_enabled: false,
+            maybe_md_yaml_tool: None,
+            yamllint_enabled: false,
+            config_files_enabled: false,
+            maybe_python_tool: None,
+            python_runner: PythonRunner::Path,
+            maybe_python_typecheck_tool: None,
+            maybe_java_kotlin_tool: None,
+            maybe_go_tool: None,
+            go_vet_enabled: false,
+            shell_enabled: false,
+            maybe_terraform_tool: None,
+            terraform_validate_enabled: false,
+            tflint_enabled: false,
+            c_cpp_enabled: false,
+            clang_tidy_enabled: false,
+            maybe_cmake_tool: None,
+            c_cpp_excluded_dirs: Vec::new(),
+            maybe_ruby_tool: None,
+            ruby_unsafe_autocorrect_enabled: false,
+            maybe_swift_tool: None,
+            maybe_php_tool: None,
+            csharp_enabled: false,
+            dart_enabled: false,
+            elixir_enabled: false,
+            zig_enabled: false,
+            dockerfile_lint_enabled: false,
+            github_actions_lint_enabled: false,
+            maybe_kubernetes_tool: None,
+            secrets_scan_enabled: false,
+            hygiene: HygieneChecks::disabled(),
+            native_runner_enabled: false,
+            cargo_manifest_dirs: Vec::new(),
+            parallel_stages_enabled: false,
+            timing_summary_enabled: false,
+            maybe_slow_stage_warn_seconds: None,
+            maybe_stage_timeout_seconds: None,
+            stage_timeout_policy: StageTimeoutPolicy::Fail,
+            stage_timeout_overrides: Vec::new(),
+        }
+    }
 }
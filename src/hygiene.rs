@@ -0,0 +1,590 @@
+//! Built-in pre-commit hygiene checks, run natively against the staged index.
+//!
+//! `git-hook-installer run pre-commit` uses this module; the managed hook delegates to it when
+//! the binary is on PATH and otherwise falls back to the equivalent POSIX-sh implementation.
+//! Fixes are applied to the index (and to the worktree file when it matches the staged content).
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::hooks::HygieneChecks;
+
+/// Git treats a blob as binary if it has a NUL byte in its first 8000 bytes.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+const ENV_EXAMPLE_FILE_NAMES: [&str; 4] =
+    [".env.example", ".env.sample", ".env.template", ".env.dist"];
+const PRIVATE_KEY_FILE_NAMES: [&str; 4] = ["id_rsa", "id_dsa", "id_ecdsa", "id_ed25519"];
+
+struct StagedEntry {
+    mode: String,
+    object_id: String,
+    path: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct AddedLine {
+    path: String,
+    line_number: usize,
+    text: String,
+}
+
+pub fn run_pre_commit_hygiene(repo_root: &Path, checks: HygieneChecks) -> Result<()> {
+    let entries = staged_entries(repo_root)?;
+    let mut failures = 0usize;
+
+    for entry in &entries {
+        if checks.sensitive_files_enabled && is_sensitive_file_name(&entry.path) {
+            println!(
+                "git-hook-installer: Refusing to commit sensitive file: {}",
+                entry.path
+            );
+            failures += 1;
+        }
+    }
+
+    let mut text_entries = Vec::new();
+    for entry in entries {
+        let contents = read_blob(repo_root, &entry.object_id)?;
+
+        if let Some(max_kb) = checks.maybe_max_file_size_kb {
+            if contents.len() as u64 > u64::from(max_kb) * 1024 {
+                println!(
+                    "git-hook-installer: {} is {} KB (limit: {max_kb} KB)",
+                    entry.path,
+                    contents.len() / 1024
+                );
+                failures += 1;
+            }
+        }
+
+        if is_binary(&contents) {
+            continue;
+        }
+
+        if checks.sensitive_files_enabled
+            && entry.path.ends_with(".pem")
+            && contains_bytes(&contents, b"PRIVATE KEY")
+        {
+            println!(
+                "git-hook-installer: Refusing to commit private key: {}",
+                entry.path
+            );
+            failures += 1;
+        }
+
+        text_entries.push((entry, contents));
+    }
+
+    if checks.conflict_markers_enabled && !text_entries.is_empty() {
+        let paths: Vec<&str> = text_entries
+            .iter()
+            .map(|(entry, _)| entry.path.as_str())
+            .collect();
+        for line in added_lines(&staged_diff(repo_root, &paths)?) {
+            if is_conflict_marker(&line.text) {
+                println!(
+                    "git-hook-installer: Merge conflict marker: {}:{}",
+                    line.path, line.line_number
+                );
+                failures += 1;
+            }
+        }
+    }
+
+    for (entry, contents) in text_entries {
+        let mut fixed = contents.clone();
+        if checks.line_endings_enabled {
+            if let Some(normalized) = normalize_mixed_line_endings(&fixed) {
+                println!(
+                    "git-hook-installer: Fixed mixed line endings: {}",
+                    entry.path
+                );
+                fixed = normalized;
+            }
+        }
+        if checks.trailing_whitespace_enabled && !keeps_trailing_whitespace(&entry.path) {
+            if let Some(stripped) = strip_trailing_whitespace(&fixed) {
+                println!(
+                    "git-hook-installer: Fixed trailing whitespace: {}",
+                    entry.path
+                );
+                fixed = stripped;
+            }
+        }
+        if checks.final_newline_enabled {
+            if let Some(terminated) = ensure_final_newline(&fixed) {
+                println!(
+                    "git-hook-installer: Fixed missing final newline: {}",
+                    entry.path
+                );
+                fixed = terminated;
+            }
+        }
+
+        if fixed != contents {
+            write_fixed_entry(repo_root, &entry, &contents, &fixed)?;
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} hygiene check(s) failed");
+    }
+    Ok(())
+}
+
+fn git(repo_root: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_root)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output.stdout)
+}
+
+fn git_with_stdin(repo_root: &Path, args: &[&str], stdin: &[u8]) -> Result<Vec<u8>> {
+    let mut child = Command::new("git")
+        .args(args)
+        .current_dir(repo_root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git")?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("Failed to open stdin for git {}", args.join(" ")))?
+        .write_all(stdin)?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Regular files (not symlinks or submodules) that are added/copied/modified/renamed in the index.
+fn staged_entries(repo_root: &Path) -> Result<Vec<StagedEntry>> {
+    let names = git(
+        repo_root,
+        &[
+            "diff",
+            "--cached",
+            "--name-only",
+            "-z",
+            "--diff-filter=ACMR",
+        ],
+    )?;
+    let paths: Vec<String> = names
+        .split(|byte| *byte == 0)
+        .filter(|name| !name.is_empty())
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+        .collect();
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut args = vec!["ls-files", "--stage", "-z", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    let listing = git(repo_root, &args)?;
+
+    let mut entries = Vec::new();
+    for record in listing.split(|byte| *byte == 0).filter(|r| !r.is_empty()) {
+        let record = String::from_utf8_lossy(record);
+        // "<mode> <object id> <stage>\t<path>"
+        let Some((meta, path)) = record.split_once('\t') else {
+            continue;
+        };
+        let mut fields = meta.split(' ');
+        let (Some(mode), Some(object_id)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if mode != "100644" && mode != "100755" {
+            continue;
+        }
+        entries.push(StagedEntry {
+            mode: mode.to_string(),
+            object_id: object_id.to_string(),
+            path: path.to_string(),
+        });
+    }
+    Ok(entries)
+}
+
+fn read_blob(repo_root: &Path, object_id: &str) -> Result<Vec<u8>> {
+    git(repo_root, &["cat-file", "blob", object_id])
+}
+
+fn staged_diff(repo_root: &Path, paths: &[&str]) -> Result<String> {
+    let mut args = vec![
+        "-c",
+        "core.quotePath=false",
+        "diff",
+        "--cached",
+        "-U0",
+        "--no-color",
+        "--no-ext-diff",
+        "--no-renames",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+        "--",
+    ];
+    args.extend_from_slice(paths);
+    Ok(String::from_utf8_lossy(&git(repo_root, &args)?).into_owned())
+}
+
+/// Stores `fixed` as the staged content, and updates the worktree file too when it still holds
+/// exactly the staged content (so unstaged edits are never overwritten).
+fn write_fixed_entry(
+    repo_root: &Path,
+    entry: &StagedEntry,
+    original: &[u8],
+    fixed: &[u8],
+) -> Result<()> {
+    let object_id = git_with_stdin(repo_root, &["hash-object", "-w", "--stdin"], fixed)?;
+    let object_id = String::from_utf8_lossy(&object_id).trim().to_string();
+    let cache_info = format!("{},{},{}", entry.mode, object_id, entry.path);
+    git(repo_root, &["update-index", "--cacheinfo", &cache_info])?;
+
+    let worktree_path = repo_root.join(&entry.path);
+    if std::fs::read(&worktree_path).ok().as_deref() == Some(original) {
+        std::fs::write(&worktree_path, fixed)
+            .with_context(|| format!("Failed to write {}", worktree_path.display()))?;
+    }
+    Ok(())
+}
+
+fn is_binary(contents: &[u8]) -> bool {
+    contents[..contents.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Trailing whitespace is content in Markdown (hard line breaks), and in patches and test
+/// snapshots, whose lines must match byte for byte.
+fn keeps_trailing_whitespace(path: &str) -> bool {
+    const EXTENSIONS: &[&str] = &[".md", ".markdown", ".patch", ".diff", ".snap"];
+    EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+        || path
+            .split('/')
+            .any(|component| component == "__snapshots__")
+}
+
+fn is_sensitive_file_name(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    if ENV_EXAMPLE_FILE_NAMES.contains(&name) {
+        return false;
+    }
+    name == ".env"
+        || name.starts_with(".env.")
+        || name.ends_with(".key")
+        || name.ends_with(".p12")
+        || name.ends_with(".pfx")
+        || PRIVATE_KEY_FILE_NAMES.contains(&name)
+}
+
+fn is_conflict_marker(line: &str) -> bool {
+    ["<<<<<<<", ">>>>>>>"].iter().any(|marker| {
+        line.strip_prefix(marker)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+    })
+}
+
+/// Parses a `git diff -U0` patch into the lines it adds (with their new line numbers).
+fn added_lines(diff: &str) -> Vec<AddedLine> {
+    let mut lines = Vec::new();
+    let mut path = String::new();
+    let mut line_number = 0usize;
+    // Inside a hunk, an added line starting with "++ " also looks like "+++ ...".
+    let mut in_header = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            in_header = true;
+            continue;
+        }
+        if in_header {
+            if let Some(new_path) = line.strip_prefix("+++ ") {
+                path = new_path.strip_prefix("b/").unwrap_or(new_path).to_string();
+                continue;
+            }
+        }
+        if let Some(hunk) = line.strip_prefix("@@ ") {
+            in_header = false;
+            // "@@ -a,b +c,d @@"
+            line_number = hunk
+                .split(' ')
+                .find_map(|range| range.strip_prefix('+'))
+                .and_then(|range| range.split(',').next())
+                .and_then(|start| start.parse().ok())
+                .unwrap_or(0);
+            continue;
+        }
+        if in_header {
+            continue;
+        }
+        if let Some(text) = line.strip_prefix('+') {
+            lines.push(AddedLine {
+                path: path.clone(),
+                line_number,
+                text: text.to_string(),
+            });
+            line_number += 1;
+        }
+    }
+    lines
+}
+
+/// If the content mixes CRLF and LF line endings, converts it to whichever is more common.
+fn normalize_mixed_line_endings(contents: &[u8]) -> Option<Vec<u8>> {
+    let lf_total = contents.iter().filter(|byte| **byte == b'\n').count();
+    let crlf = contents.windows(2).filter(|pair| pair == b"\r\n").count();
+    let lf_only = lf_total - crlf;
+    if crlf == 0 || lf_only == 0 {
+        return None;
+    }
+
+    let to_crlf = crlf > lf_only;
+    let mut normalized = Vec::with_capacity(contents.len() + lf_only);
+    for (idx, byte) in contents.iter().enumerate() {
+        if *byte == b'\n' {
+            let has_cr = idx > 0 && contents[idx - 1] == b'\r';
+            if to_crlf && !has_cr {
+                normalized.push(b'\r');
+            }
+            if !to_crlf && has_cr {
+                normalized.pop();
+            }
+        }
+        normalized.push(*byte);
+    }
+    Some(normalized)
+}
+
+/// Strips spaces/tabs before each line ending (CRLF or LF) and at the end of the content.
+fn strip_trailing_whitespace(contents: &[u8]) -> Option<Vec<u8>> {
+    let mut stripped = Vec::with_capacity(contents.len());
+    for line in contents.split_inclusive(|byte| *byte == b'\n') {
+        let ending_len = if line.ends_with(b"\r\n") {
+            2
+        } else if line.ends_with(b"\n") {
+            1
+        } else {
+            0
+        };
+        let (body, ending) = line.split_at(line.len() - ending_len);
+        let trimmed_len = body
+            .iter()
+            .rposition(|byte| *byte != b' ' && *byte != b'\t')
+            .map_or(0, |idx| idx + 1);
+        stripped.extend_from_slice(&body[..trimmed_len]);
+        stripped.extend_from_slice(ending);
+    }
+    (stripped != contents).then_some(stripped)
+}
+
+/// Appends a line ending (CRLF if the content already uses CRLF) to non-empty content.
+fn ensure_final_newline(contents: &[u8]) -> Option<Vec<u8>> {
+    if contents.is_empty() || contents.ends_with(b"\n") {
+        return None;
+    }
+    let mut terminated = contents.to_vec();
+    if contains_bytes(contents, b"\r\n") {
+        terminated.extend_from_slice(b"\r\n");
+    } else {
+        terminated.push(b'\n');
+    }
+    Some(terminated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn normalize_mixed_line_endings_uses_majority_ending() {
+        // arrange
+        let contents = b"a\r\nb\r\nc\n";
+
+        // act
+        let normalized = normalize_mixed_line_endings(contents);
+
+        // assert
+        assert_eq!(normalized.as_deref(), Some(&b"a\r\nb\r\nc\r\n"[..]));
+        assert_eq!(normalize_mixed_line_endings(b"a\nb\n"), None);
+    }
+
+    #[test]
+    fn strip_trailing_whitespace_keeps_crlf_endings() {
+        // arrange
+        let contents = b"a \r\nb\t\nc  ";
+
+        // act
+        let stripped = strip_trailing_whitespace(contents);
+
+        // assert
+        assert_eq!(stripped.as_deref(), Some(&b"a\r\nb\nc"[..]));
+    }
+
+    #[test]
+    fn keeps_trailing_whitespace_in_markdown_patches_and_snapshots() {
+        // arrange
+        let paths = [
+            "README.md",
+            "patches/fix.patch",
+            "upstream.diff",
+            "tests/snapshots/render.snap",
+            "src/__snapshots__/view.test.js.snap",
+            "src/__snapshots__/output.txt",
+        ];
+
+        // act
+        let all_kept = paths.iter().all(|path| keeps_trailing_whitespace(path));
+
+        // assert
+        assert!(all_kept);
+        assert!(!keeps_trailing_whitespace("src/main.rs"));
+    }
+
+    #[test]
+    fn added_lines_tracks_new_line_numbers() {
+        // arrange
+        let diff = "diff --git a/m.txt b/m.txt\n\
+                    --- a/m.txt\n\
+                    +++ b/m.txt\n\
+                    @@ -1,0 +2,2 @@\n\
+                    +<<<<<<< HEAD\n\
+                    +ours\n";
+
+        // act
+        let lines = added_lines(diff);
+
+        // assert
+        assert_eq!(
+            lines,
+            vec![
+                AddedLine {
+                    path: "m.txt".to_string(),
+                    line_number: 2,
+                    text: "<<<<<<< HEAD".to_string(),
+                },
+                AddedLine {
+                    path: "m.txt".to_string(),
+                    line_number: 3,
+                    text: "ours".to_string(),
+                },
+            ]
+        );
+        assert!(is_conflict_marker(&lines[0].text));
+    }
+
+    #[test]
+    fn added_lines_keeps_content_that_looks_like_a_file_header() {
+        // arrange
+        let diff = "diff --git a/m.txt b/m.txt\n\
+                    new file mode 100644\n\
+                    --- /dev/null\n\
+                    +++ b/m.txt\n\
+                    @@ -0,0 +1,2 @@\n\
+                    +++ b/other.txt\n\
+                    +>>>>>>> theirs\n";
+
+        // act
+        let lines = added_lines(diff);
+
+        // assert
+        assert_eq!(
+            lines,
+            vec![
+                AddedLine {
+                    path: "m.txt".to_string(),
+                    line_number: 1,
+                    text: "++ b/other.txt".to_string(),
+                },
+                AddedLine {
+                    path: "m.txt".to_string(),
+                    line_number: 2,
+                    text: ">>>>>>> theirs".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn is_sensitive_file_name_allows_env_examples() {
+        // arrange
+        let paths = [
+            "config/.env",
+            ".env.production",
+            "keys/id_ed25519",
+            "tls/server.key",
+        ];
+
+        // act
+        let all_sensitive = paths.iter().all(|path| is_sensitive_file_name(path));
+
+        // assert
+        assert!(all_sensitive);
+        assert!(!is_sensitive_file_name("app/.env.example"));
+    }
+
+    #[test]
+    fn run_pre_commit_hygiene_fixes_staged_content_only() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let repo_root = temp.path();
+        git(repo_root, &["init", "-q"])?;
+        std::fs::write(repo_root.join("notes.txt"), "staged  \nline")?;
+        git(repo_root, &["add", "notes.txt"])?;
+        // An unstaged edit must survive the fix.
+        std::fs::write(repo_root.join("notes.txt"), "unstaged  \n")?;
+
+        // act
+        run_pre_commit_hygiene(repo_root, HygieneChecks::default())?;
+
+        // assert
+        let staged = git(repo_root, &["show", ":notes.txt"])?;
+        assert_eq!(staged, b"staged\nline\n");
+        assert_eq!(
+            std::fs::read_to_string(repo_root.join("notes.txt"))?,
+            "unstaged  \n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_pre_commit_hygiene_rejects_env_file() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let repo_root = temp.path();
+        git(repo_root, &["init", "-q"])?;
+        std::fs::write(repo_root.join(".env"), "TOKEN=abc\n")?;
+        git(repo_root, &["add", ".env"])?;
+
+        // act
+        let result = run_pre_commit_hygiene(repo_root, HygieneChecks::default());
+
+        // assert
+        assert!(result.is_err());
+        Ok(())
+    }
+}
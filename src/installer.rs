@@ -4,7 +4,7 @@
 //! (including user prompts when needed) and then installing the resolved hook
 //! into the git repository.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use dialoguer::Confirm;
@@ -26,7 +26,7 @@ pub enum ResolvedHook {
 
 pub fn resolve_hook_kind(
     maybe_hook: Option<HookKind>,
-    maybe_manifest_dir_from_cli: Option<&Path>,
+    manifest_dirs_from_cli: &[PathBuf],
     cwd: &Path,
     repo_root: &Path,
     options: ResolveHookOptions,
@@ -35,8 +35,8 @@ pub fn resolve_hook_kind(
 
     match hook {
         HookKind::PreCommit => {
-            let maybe_cargo_dir = detect::resolve_cargo_dir_best_effort(
-                maybe_manifest_dir_from_cli,
+            let cargo_dirs = detect::resolve_cargo_dirs_best_effort(
+                manifest_dirs_from_cli,
                 cwd,
                 repo_root,
                 ResolveHookOptions {
@@ -45,8 +45,7 @@ pub fn resolve_hook_kind(
                 },
             );
 
-            let settings =
-                prompts::resolve_pre_commit_settings(repo_root, maybe_cargo_dir, options)?;
+            let settings = prompts::resolve_pre_commit_settings(repo_root, cargo_dirs, options)?;
 
             if options.non_interactive || options.yes {
                 return Ok(Some(ResolvedHook::PreCommit { settings }));
@@ -77,7 +76,7 @@ pub fn install_resolved_hook(
     match kind {
         ResolvedHook::PreCommit { settings } => {
             // Note: settings are stored inside the managed block itself (no repo config).
-            // We still want the managed block to have an absolute manifest dir if present.
+            // Cargo manifest dirs are written relative to the repo root.
             let block = managed_pre_commit_block(&settings, repo_root);
             upsert_managed_pre_commit_hook(git_dir, &block, options)
         }
@@ -1,7 +1,10 @@
 use std::path::{Path, PathBuf};
 
-use crate::cargo_repo::{resolve_cargo_manifest_dir, ResolveHookOptions};
-use crate::hooks::{JavaKotlinTool, JsTsTool, PythonTool};
+use crate::cargo_repo::{resolve_cargo_manifest_dirs, ResolveHookOptions};
+use crate::hooks::{
+    CmakeTool, GoTool, JavaKotlinTool, JsPackageManager, JsTsTool, KubernetesTool, MdYamlTool,
+    PhpTool, PythonRunner, PythonTool, PythonTypecheckTool, RubyTool, SwiftTool, TerraformTool,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToolChoiceKind {
@@ -16,17 +19,17 @@ pub struct ToolChoice<T> {
     pub maybe_reason: Option<&'static str>,
 }
 
-pub fn resolve_cargo_dir_best_effort(
-    maybe_manifest_dir_from_cli: Option<&Path>,
+pub fn resolve_cargo_dirs_best_effort(
+    manifest_dirs_from_cli: &[PathBuf],
     cwd: &Path,
     repo_root: &Path,
     options: ResolveHookOptions,
-) -> Option<PathBuf> {
-    let result = resolve_cargo_manifest_dir(maybe_manifest_dir_from_cli, cwd, repo_root, options);
-    let Ok(cargo_dir) = result else {
-        return None;
+) -> Vec<PathBuf> {
+    let result = resolve_cargo_manifest_dirs(manifest_dirs_from_cli, cwd, repo_root, options);
+    let Ok(cargo_dirs) = result else {
+        return Vec::new();
     };
-    Some(cargo_dir)
+    cargo_dirs
 }
 
 pub fn choose_js_ts_tool(repo_root: &Path) -> ToolChoice<JsTsTool> {
@@ -57,6 +60,78 @@ pub fn choose_js_ts_tool(repo_root: &Path) -> ToolChoice<JsTsTool> {
     }
 }
 
+pub fn choose_js_package_manager(repo_root: &Path) -> ToolChoice<JsPackageManager> {
+    // An explicit `packageManager` field (Corepack) wins over lockfile heuristics.
+    let package_json = repo_root.join("package.json");
+    if let Ok(contents) = std::fs::read_to_string(&package_json) {
+        let maybe_package_manager = package_json_package_manager(&contents);
+        let maybe_tool = maybe_package_manager.and_then(|name| match name {
+            "npm" => Some(JsPackageManager::Npm),
+            "pnpm" => Some(JsPackageManager::Pnpm),
+            "yarn" => Some(JsPackageManager::Yarn),
+            "bun" => Some(JsPackageManager::Bun),
+            _ => None,
+        });
+        if let Some(tool) = maybe_tool {
+            return ToolChoice {
+                tool,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found packageManager in package.json"),
+            };
+        }
+    }
+
+    let lockfiles = [
+        (
+            "pnpm-lock.yaml",
+            JsPackageManager::Pnpm,
+            "found pnpm-lock.yaml",
+        ),
+        ("yarn.lock", JsPackageManager::Yarn, "found yarn.lock"),
+        (
+            "bun.lockb",
+            JsPackageManager::Bun,
+            "found bun.lockb/bun.lock",
+        ),
+        (
+            "bun.lock",
+            JsPackageManager::Bun,
+            "found bun.lockb/bun.lock",
+        ),
+        (
+            "package-lock.json",
+            JsPackageManager::Npm,
+            "found package-lock.json",
+        ),
+    ];
+    for (name, tool, reason) in lockfiles {
+        if repo_root.join(name).is_file() {
+            return ToolChoice {
+                tool,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some(reason),
+            };
+        }
+    }
+
+    ToolChoice {
+        tool: JsPackageManager::Npm,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+/// Extracts the package manager name from `"packageManager": "pnpm@9.1.0"`.
+fn package_json_package_manager(contents: &str) -> Option<&str> {
+    // Not a JSON parser; `packageManager` is a flat string field, so a targeted scan is enough.
+    let (_, rest) = contents.split_once("\"packageManager\"")?;
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let value = rest.strip_prefix('"')?;
+    let (value, _) = value.split_once('"')?;
+    let name = value.split('@').next()?.trim();
+    Some(name)
+}
+
 pub fn detect_js_ts_repo_proof(repo_root: &Path) -> Option<&'static str> {
     // Strong signals at repo root.
     let root_signals = [
@@ -66,6 +141,7 @@ pub fn detect_js_ts_repo_proof(repo_root: &Path) -> Option<&'static str> {
         "deno.json",
         "deno.jsonc",
         "bun.lockb",
+        "bun.lock",
         "pnpm-lock.yaml",
         "yarn.lock",
         "package-lock.json",
@@ -180,6 +256,31 @@ pub fn detect_go_repo_proof(repo_root: &Path) -> Option<&'static str> {
     None
 }
 
+pub fn choose_go_tool(repo_root: &Path) -> ToolChoice<GoTool> {
+    let golangci_configs = [
+        ".golangci.yml",
+        ".golangci.yaml",
+        ".golangci.toml",
+        ".golangci.json",
+    ];
+    if golangci_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return ToolChoice {
+            tool: GoTool::GolangciLint,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .golangci.yml/.golangci.yaml/.golangci.toml"),
+        };
+    }
+
+    ToolChoice {
+        tool: GoTool::Goimports,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
 pub fn detect_ruby_repo_proof(repo_root: &Path) -> Option<&'static str> {
     let root_signals = ["Gemfile", "Gemfile.lock", ".ruby-version", "Rakefile"];
     if root_signals
@@ -200,192 +301,832 @@ pub fn detect_ruby_repo_proof(repo_root: &Path) -> Option<&'static str> {
     None
 }
 
-pub fn detect_shell_repo_proof(repo_root: &Path) -> Option<&'static str> {
-    let root_signals = [".shellcheckrc", ".shfmt"];
+pub fn choose_ruby_tool(repo_root: &Path) -> ToolChoice<RubyTool> {
+    if repo_root.join(".standard.yml").is_file() {
+        return ToolChoice {
+            tool: RubyTool::Standard,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .standard.yml"),
+        };
+    }
+
+    if let Ok(contents) = std::fs::read_to_string(repo_root.join("Gemfile.lock")) {
+        // Gemfile.lock lists resolved gems as `    name (version)`.
+        if contents
+            .lines()
+            .any(|line| line.starts_with("    standard ("))
+        {
+            return ToolChoice {
+                tool: RubyTool::Standard,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found standard in Gemfile.lock"),
+            };
+        }
+        if contents
+            .lines()
+            .any(|line| line.starts_with("    rubocop ("))
+        {
+            return ToolChoice {
+                tool: RubyTool::Rubocop,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found rubocop in Gemfile.lock"),
+            };
+        }
+    }
+
+    if let Ok(contents) = std::fs::read_to_string(repo_root.join("Gemfile")) {
+        if contents.contains("gem \"standard\"") || contents.contains("gem 'standard'") {
+            return ToolChoice {
+                tool: RubyTool::Standard,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found standard in Gemfile"),
+            };
+        }
+    }
+
+    ToolChoice {
+        tool: RubyTool::Rubocop,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_swift_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = ["Package.swift", ".swift-format", ".swiftlint.yml"];
     if root_signals
         .iter()
         .any(|name| repo_root.join(name).is_file())
     {
-        return Some("found shell tooling file (.shellcheckrc/.shfmt)");
+        return Some("found Swift tooling file (Package.swift/.swift-format/.swiftlint.yml)");
     }
 
-    if has_any_file_with_ext_bounded(repo_root, &["sh", "bash", "zsh"], 2, 10_000) {
-        return Some("found shell scripts (shallow scan)");
+    // `*.xcodeproj` is a directory; its `project.pbxproj` is what identifies it.
+    if has_any_file_named_bounded(repo_root, &["Package.swift", "project.pbxproj"], 3, 10_000) {
+        return Some("found nested Swift package or Xcode project (shallow scan)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["swift"], 2, 10_000) {
+        return Some("found Swift source files (shallow scan)");
     }
 
     None
 }
 
-pub fn detect_terraform_repo_proof(repo_root: &Path) -> Option<&'static str> {
-    let root_signals = [".terraform.lock.hcl"];
+pub fn choose_swift_tool(repo_root: &Path) -> ToolChoice<SwiftTool> {
+    if repo_root.join(".swift-format").is_file() {
+        return ToolChoice {
+            tool: SwiftTool::SwiftFormat,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .swift-format"),
+        };
+    }
+
+    if repo_root.join(".swiftlint.yml").is_file() {
+        return ToolChoice {
+            tool: SwiftTool::Swiftlint,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .swiftlint.yml"),
+        };
+    }
+
+    ToolChoice {
+        tool: SwiftTool::SwiftFormat,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_php_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = [
+        "composer.json",
+        "composer.lock",
+        ".php-cs-fixer.php",
+        ".php-cs-fixer.dist.php",
+        "phpcs.xml",
+        "phpcs.xml.dist",
+        "pint.json",
+    ];
     if root_signals
         .iter()
         .any(|name| repo_root.join(name).is_file())
     {
-        return Some("found Terraform lockfile (.terraform.lock.hcl)");
+        return Some("found PHP tooling file (composer.json/php-cs-fixer/phpcs/pint config)");
     }
 
     if has_any_file_named_bounded(repo_root, &root_signals, 3, 10_000) {
-        return Some("found nested Terraform lockfile (shallow scan)");
+        return Some("found nested PHP tooling file (shallow scan)");
     }
 
-    if has_any_file_with_ext_bounded(repo_root, &["tf", "tfvars"], 2, 10_000) {
-        return Some("found Terraform files (shallow scan)");
+    if has_any_file_with_ext_bounded(repo_root, &["php"], 2, 10_000) {
+        return Some("found PHP source files (shallow scan)");
     }
 
     None
 }
 
-pub fn detect_c_cpp_repo_proof(repo_root: &Path) -> Option<&'static str> {
-    let root_signals = [".clang-format"];
+pub fn choose_php_tool(repo_root: &Path) -> ToolChoice<PhpTool> {
+    let composer_json =
+        std::fs::read_to_string(repo_root.join("composer.json")).unwrap_or_default();
+
+    if repo_root.join("pint.json").is_file() || composer_json.contains("\"laravel/pint\"") {
+        return ToolChoice {
+            tool: PhpTool::Pint,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found pint.json or laravel/pint in composer.json"),
+        };
+    }
+
+    if repo_root.join(".php-cs-fixer.php").is_file()
+        || repo_root.join(".php-cs-fixer.dist.php").is_file()
+        || composer_json.contains("\"friendsofphp/php-cs-fixer\"")
+    {
+        return ToolChoice {
+            tool: PhpTool::PhpCsFixer,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some(
+                "found .php-cs-fixer.php or friendsofphp/php-cs-fixer in composer.json",
+            ),
+        };
+    }
+
+    if repo_root.join("phpcs.xml").is_file()
+        || repo_root.join("phpcs.xml.dist").is_file()
+        || composer_json.contains("\"squizlabs/php_codesniffer\"")
+    {
+        return ToolChoice {
+            tool: PhpTool::Phpcbf,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found phpcs.xml or squizlabs/php_codesniffer in composer.json"),
+        };
+    }
+
+    ToolChoice {
+        tool: PhpTool::PhpCsFixer,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_csharp_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join("global.json").is_file() {
+        return Some("found .NET SDK file (global.json)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["sln", "csproj"], 3, 10_000) {
+        return Some("found .NET solution/project file (*.sln/*.csproj)");
+    }
+
+    let editorconfig = std::fs::read_to_string(repo_root.join(".editorconfig")).unwrap_or_default();
+    if editorconfig_has_csharp_section(&editorconfig) {
+        return Some("found C# section in .editorconfig");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["cs"], 2, 10_000) {
+        return Some("found C# source files (shallow scan)");
+    }
+
+    None
+}
+
+pub fn detect_dart_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join("pubspec.yaml").is_file() {
+        return Some("found Dart/Flutter package file (pubspec.yaml)");
+    }
+
+    if has_any_file_named_bounded(repo_root, &["pubspec.yaml"], 3, 10_000) {
+        return Some("found nested Dart/Flutter package file (shallow scan)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["dart"], 2, 10_000) {
+        return Some("found Dart source files (shallow scan)");
+    }
+
+    None
+}
+
+pub fn detect_elixir_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join("mix.exs").is_file() {
+        return Some("found Elixir project file (mix.exs)");
+    }
+
+    if has_any_file_named_bounded(repo_root, &["mix.exs"], 3, 10_000) {
+        return Some("found nested Elixir project file (shallow scan)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["ex", "exs"], 2, 10_000) {
+        return Some("found Elixir source files (shallow scan)");
+    }
+
+    None
+}
+
+pub fn detect_zig_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = ["build.zig", "build.zig.zon"];
     if root_signals
         .iter()
         .any(|name| repo_root.join(name).is_file())
     {
-        return Some("found clang-format config (.clang-format)");
+        return Some("found Zig build file (build.zig/build.zig.zon)");
     }
 
-    if has_any_file_with_ext_bounded(
-        repo_root,
-        &["c", "cc", "cpp", "cxx", "h", "hh", "hpp", "hxx"],
-        2,
-        10_000,
-    ) {
-        return Some("found C/C++ files (shallow scan)");
+    if has_any_file_named_bounded(repo_root, &root_signals, 3, 10_000) {
+        return Some("found nested Zig build file (shallow scan)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["zig"], 2, 10_000) {
+        return Some("found Zig source files (shallow scan)");
     }
 
     None
 }
 
-pub fn detect_typescript_repo_proof(repo_root: &Path) -> Option<&'static str> {
-    // Strong signal: a tsconfig exists (root or nested in common monorepo layouts).
-    if repo_root.join("tsconfig.json").is_file() {
-        return Some("found tsconfig.json");
+pub fn detect_md_yaml_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if has_dprint_config(repo_root)
+        || has_markdownlint_or_yamlfmt_config(repo_root)
+        || has_yamllint_config(repo_root)
+    {
+        return Some(
+            "found Markdown/YAML tooling file (dprint/markdownlint/yamlfmt/yamllint config)",
+        );
     }
-    if has_any_file_named_bounded(repo_root, &["tsconfig.json"], 3, 10_000) {
-        return Some("found nested tsconfig.json (shallow scan)");
+
+    if has_prettier_config(repo_root) {
+        return Some("found Prettier config");
     }
 
-    // Fallback: find TS sources in a bounded scan.
-    if has_any_file_with_ext_bounded(repo_root, &["ts", "tsx"], 2, 10_000) {
-        return Some("found TypeScript source files (shallow scan)");
+    if has_any_file_with_ext_bounded(repo_root, &["yml", "yaml"], 2, 10_000) {
+        return Some("found YAML files (shallow scan)");
+    }
+
+    // Nearly every repo has a README, so Markdown alone only counts where Prettier (the default
+    // with a package.json) would format it.
+    if repo_root.join("package.json").is_file()
+        && has_any_file_with_ext_bounded(repo_root, &["md", "markdown"], 2, 10_000)
+    {
+        return Some("found Markdown files in a JS repo (shallow scan)");
     }
 
     None
 }
 
-pub fn choose_python_tool(repo_root: &Path) -> ToolChoice<PythonTool> {
-    // Prefer Ruff if it appears configured; otherwise fall back to Black if configured.
-    // Default to Ruff because it can both format and lint-fix.
-    if has_ruff_config(repo_root) {
+pub fn choose_md_yaml_tool(repo_root: &Path) -> ToolChoice<MdYamlTool> {
+    if has_dprint_config(repo_root) {
         return ToolChoice {
-            tool: PythonTool::Ruff,
+            tool: MdYamlTool::Dprint,
             kind: ToolChoiceKind::Detected,
-            maybe_reason: Some("found ruff.toml/.ruff.toml or [tool.ruff] in pyproject.toml"),
+            maybe_reason: Some("found dprint config"),
         };
     }
-    if has_black_config(repo_root) {
+
+    if has_prettier_config(repo_root) {
         return ToolChoice {
-            tool: PythonTool::Black,
+            tool: MdYamlTool::Prettier,
             kind: ToolChoiceKind::Detected,
-            maybe_reason: Some("found black.toml or [tool.black] in pyproject.toml"),
+            maybe_reason: Some("found prettier config"),
         };
     }
-    ToolChoice {
-        tool: PythonTool::Ruff,
-        kind: ToolChoiceKind::Default,
-        maybe_reason: None,
-    }
-}
 
-pub fn choose_java_kotlin_tool(repo_root: &Path) -> ToolChoice<JavaKotlinTool> {
-    // Prefer Spotless if this looks like a Gradle project.
-    if has_gradle_project(repo_root) {
+    if has_markdownlint_or_yamlfmt_config(repo_root) {
         return ToolChoice {
-            tool: JavaKotlinTool::Spotless,
+            tool: MdYamlTool::MarkdownlintYamlfmt,
             kind: ToolChoiceKind::Detected,
-            maybe_reason: Some("found gradlew/build.gradle/build.gradle.kts"),
+            maybe_reason: Some("found markdownlint/yamlfmt config"),
+        };
+    }
+
+    // Prettier is the natural default when the repo already has a JS toolchain to pin it.
+    if repo_root.join("package.json").is_file() {
+        return ToolChoice {
+            tool: MdYamlTool::Prettier,
+            kind: ToolChoiceKind::Default,
+            maybe_reason: None,
         };
     }
 
     ToolChoice {
-        tool: JavaKotlinTool::Ktlint,
+        tool: MdYamlTool::MarkdownlintYamlfmt,
         kind: ToolChoiceKind::Default,
         maybe_reason: None,
     }
 }
 
-fn has_prettier_or_eslint_config(repo_root: &Path) -> bool {
-    let prettier_configs = [
-        ".prettierrc",
-        ".prettierrc.json",
-        ".prettierrc.yaml",
-        ".prettierrc.yml",
-        ".prettierrc.js",
-        ".prettierrc.cjs",
-        "prettier.config.js",
-        "prettier.config.cjs",
-        "prettier.config.mjs",
-    ];
-    if prettier_configs
-        .iter()
-        .any(|name| repo_root.join(name).is_file())
-    {
-        return true;
+/// yamllint only checks (it never rewrites files), so it's opt-in via the repo's own config.
+pub fn detect_yamllint_config(repo_root: &Path) -> Option<&'static str> {
+    if has_yamllint_config(repo_root) {
+        return Some("found yamllint config");
     }
 
-    let eslint_configs = [
-        ".eslintrc",
-        ".eslintrc.json",
-        ".eslintrc.yaml",
-        ".eslintrc.yml",
-        ".eslintrc.js",
-        ".eslintrc.cjs",
-        "eslint.config.js",
-        "eslint.config.cjs",
-        "eslint.config.mjs",
-    ];
-    if eslint_configs
-        .iter()
-        .any(|name| repo_root.join(name).is_file())
+    None
+}
+
+pub fn detect_config_files_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = ["taplo.toml", ".taplo.toml"];
+    if has_dprint_config(repo_root)
+        || root_signals
+            .iter()
+            .any(|name| repo_root.join(name).is_file())
     {
-        return true;
+        return Some("found config formatter file (taplo/dprint config)");
     }
 
-    // Heuristic: some repos store config in package.json.
-    let package_json = repo_root.join("package.json");
-    let Ok(contents) = std::fs::read_to_string(&package_json) else {
-        return false;
-    };
+    if has_any_file_with_ext_bounded(repo_root, &["toml", "json"], 2, 10_000) {
+        return Some("found TOML/JSON files (shallow scan)");
+    }
 
-    // Keep this heuristic intentionally simple: we're not parsing JSON, just looking for strong signals.
-    contents.contains("\"eslintConfig\"")
-        || contents.contains("\"prettier\"")
-        || contents.contains("\"eslint\"")
+    None
 }
 
-fn has_ruff_config(repo_root: &Path) -> bool {
-    if repo_root.join("ruff.toml").is_file() || repo_root.join(".ruff.toml").is_file() {
-        return true;
+pub fn detect_dockerfile_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if has_root_file_with_prefix(repo_root, &["Dockerfile", "Containerfile"]) {
+        return Some("found Dockerfile/Containerfile");
     }
 
-    let pyproject = repo_root.join("pyproject.toml");
-    let Ok(contents) = std::fs::read_to_string(&pyproject) else {
-        return false;
-    };
-    contents.contains("[tool.ruff]")
+    if has_any_file_named_bounded(repo_root, &["Dockerfile", "Containerfile"], 3, 10_000) {
+        return Some("found nested Dockerfile/Containerfile (shallow scan)");
+    }
+
+    None
 }
 
-fn has_black_config(repo_root: &Path) -> bool {
-    if repo_root.join("black.toml").is_file() {
-        return true;
+pub fn detect_github_actions_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join(".github").join("workflows").is_dir() {
+        return Some("found .github/workflows");
     }
 
-    let pyproject = repo_root.join("pyproject.toml");
-    let Ok(contents) = std::fs::read_to_string(&pyproject) else {
-        return false;
-    };
-    contents.contains("[tool.black]")
+    None
+}
+
+pub fn detect_kubernetes_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if has_dir_named_shallow(repo_root, &["k8s", "kubernetes"]) {
+        return Some("found k8s/ or kubernetes/ manifest directory");
+    }
+
+    None
+}
+
+pub fn choose_kubernetes_tool(repo_root: &Path) -> ToolChoice<KubernetesTool> {
+    let kube_linter_configs = [".kube-linter.yaml", ".kube-linter.yml"];
+    if kube_linter_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return ToolChoice {
+            tool: KubernetesTool::KubeLinter,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .kube-linter.yaml"),
+        };
+    }
+
+    ToolChoice {
+        tool: KubernetesTool::Kubeconform,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_shell_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = [".shellcheckrc", ".shfmt"];
+    if root_signals
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return Some("found shell tooling file (.shellcheckrc/.shfmt)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["sh", "bash", "zsh"], 2, 10_000) {
+        return Some("found shell scripts (shallow scan)");
+    }
+
+    None
+}
+
+pub fn detect_terraform_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = [".terraform.lock.hcl"];
+    if root_signals
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return Some("found Terraform lockfile (.terraform.lock.hcl)");
+    }
+
+    if has_any_file_named_bounded(repo_root, &root_signals, 3, 10_000) {
+        return Some("found nested Terraform lockfile (shallow scan)");
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["tf", "tfvars"], 2, 10_000) {
+        return Some("found Terraform files (shallow scan)");
+    }
+
+    None
+}
+
+pub fn choose_terraform_tool(repo_root: &Path) -> ToolChoice<TerraformTool> {
+    // Modules often live in subdirectories, each with its own lockfile (as in detection).
+    let is_opentofu_lockfile = |lockfile: &Path| {
+        std::fs::read_to_string(lockfile)
+            .is_ok_and(|contents| contents.contains("registry.opentofu.org"))
+    };
+    if has_any_file_named_bounded_where(
+        repo_root,
+        &[".terraform.lock.hcl"],
+        3,
+        10_000,
+        is_opentofu_lockfile,
+    ) {
+        return ToolChoice {
+            tool: TerraformTool::OpenTofu,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found OpenTofu providers in .terraform.lock.hcl"),
+        };
+    }
+
+    ToolChoice {
+        tool: TerraformTool::Terraform,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_tflint_config(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join(".tflint.hcl").is_file() {
+        return Some("found .tflint.hcl");
+    }
+
+    if has_any_file_named_bounded(repo_root, &[".tflint.hcl"], 3, 10_000) {
+        return Some("found nested .tflint.hcl (shallow scan)");
+    }
+
+    None
+}
+
+pub fn detect_c_cpp_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    let root_signals = [".clang-format"];
+    if root_signals
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return Some("found clang-format config (.clang-format)");
+    }
+
+    if has_any_file_with_ext_bounded(
+        repo_root,
+        &["c", "cc", "cpp", "cxx", "h", "hh", "hpp", "hxx"],
+        2,
+        10_000,
+    ) {
+        return Some("found C/C++ files (shallow scan)");
+    }
+
+    if repo_root.join("CMakeLists.txt").is_file() {
+        return Some("found CMake project file (CMakeLists.txt)");
+    }
+
+    None
+}
+
+/// `clang-tidy --fix` only runs with the repo's own check set: a compilation database alone would
+/// apply the default checks' fixes. The database is usually generated (and ignored), so the hook
+/// skips clang-tidy until it exists.
+pub fn detect_clang_tidy_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join(".clang-tidy").is_file() {
+        return Some("found .clang-tidy");
+    }
+
+    None
+}
+
+pub fn detect_cmake_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    if repo_root.join("CMakeLists.txt").is_file() {
+        return Some("found CMakeLists.txt");
+    }
+
+    if has_any_file_named_bounded(repo_root, &["CMakeLists.txt"], 2, 10_000) {
+        return Some("found nested CMakeLists.txt (shallow scan)");
+    }
+
+    None
+}
+
+pub fn choose_cmake_tool(repo_root: &Path) -> ToolChoice<CmakeTool> {
+    let gersemi_configs = [".gersemirc", ".gersemirc.yaml", ".gersemirc.yml"];
+    if gersemi_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return ToolChoice {
+            tool: CmakeTool::Gersemi,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .gersemirc"),
+        };
+    }
+
+    let cmake_format_configs = [
+        ".cmake-format.yaml",
+        ".cmake-format.json",
+        ".cmake-format.py",
+        "cmake-format.yaml",
+        "cmake-format.json",
+        "cmake-format.py",
+    ];
+    if cmake_format_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return ToolChoice {
+            tool: CmakeTool::CmakeFormat,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found cmake-format config"),
+        };
+    }
+
+    ToolChoice {
+        tool: CmakeTool::CmakeFormat,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn detect_typescript_repo_proof(repo_root: &Path) -> Option<&'static str> {
+    // Strong signal: a tsconfig exists (root or nested in common monorepo layouts).
+    if repo_root.join("tsconfig.json").is_file() {
+        return Some("found tsconfig.json");
+    }
+    if has_any_file_named_bounded(repo_root, &["tsconfig.json"], 3, 10_000) {
+        return Some("found nested tsconfig.json (shallow scan)");
+    }
+
+    // Fallback: find TS sources in a bounded scan.
+    if has_any_file_with_ext_bounded(repo_root, &["ts", "tsx"], 2, 10_000) {
+        return Some("found TypeScript source files (shallow scan)");
+    }
+
+    None
+}
+
+pub fn choose_python_tool(repo_root: &Path) -> ToolChoice<PythonTool> {
+    // Prefer Ruff if it appears configured; otherwise fall back to Black if configured.
+    // Default to Ruff because it can both format and lint-fix.
+    if has_ruff_config(repo_root) {
+        return ToolChoice {
+            tool: PythonTool::Ruff,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found ruff.toml/.ruff.toml or [tool.ruff] in pyproject.toml"),
+        };
+    }
+    if has_black_config(repo_root) {
+        return ToolChoice {
+            tool: PythonTool::Black,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found black.toml or [tool.black] in pyproject.toml"),
+        };
+    }
+    ToolChoice {
+        tool: PythonTool::Ruff,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+/// Python type checking is opt-in: it is only enabled when the repo already configures a checker.
+pub fn choose_python_typecheck_tool(repo_root: &Path) -> Option<ToolChoice<PythonTypecheckTool>> {
+    let pyproject = std::fs::read_to_string(repo_root.join("pyproject.toml")).unwrap_or_default();
+    let setup_cfg = std::fs::read_to_string(repo_root.join("setup.cfg")).unwrap_or_default();
+
+    if repo_root.join("mypy.ini").is_file()
+        || repo_root.join(".mypy.ini").is_file()
+        || pyproject.contains("[tool.mypy]")
+        || setup_cfg.contains("[mypy]")
+    {
+        return Some(ToolChoice {
+            tool: PythonTypecheckTool::Mypy,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found mypy.ini or [tool.mypy] in pyproject.toml"),
+        });
+    }
+
+    if repo_root.join("pyrightconfig.json").is_file() || pyproject.contains("[tool.pyright]") {
+        return Some(ToolChoice {
+            tool: PythonTypecheckTool::Pyright,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found pyrightconfig.json or [tool.pyright] in pyproject.toml"),
+        });
+    }
+
+    None
+}
+
+pub fn choose_python_runner(repo_root: &Path) -> ToolChoice<PythonRunner> {
+    let pyproject = std::fs::read_to_string(repo_root.join("pyproject.toml")).unwrap_or_default();
+
+    if repo_root.join("uv.lock").is_file() || pyproject.contains("[tool.uv]") {
+        return ToolChoice {
+            tool: PythonRunner::Uv,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found uv.lock or [tool.uv] in pyproject.toml"),
+        };
+    }
+    if repo_root.join("poetry.lock").is_file() || pyproject.contains("[tool.poetry]") {
+        return ToolChoice {
+            tool: PythonRunner::Poetry,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found poetry.lock or [tool.poetry] in pyproject.toml"),
+        };
+    }
+    if repo_root.join("Pipfile").is_file() || repo_root.join("Pipfile.lock").is_file() {
+        return ToolChoice {
+            tool: PythonRunner::Pipenv,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found Pipfile/Pipfile.lock"),
+        };
+    }
+    if repo_root.join("hatch.toml").is_file() || pyproject.contains("[tool.hatch.envs") {
+        return ToolChoice {
+            tool: PythonRunner::Hatch,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found hatch.toml or [tool.hatch.envs] in pyproject.toml"),
+        };
+    }
+    if repo_root.join(".venv").is_dir() {
+        return ToolChoice {
+            tool: PythonRunner::Venv,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found .venv"),
+        };
+    }
+
+    ToolChoice {
+        tool: PythonRunner::Path,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+pub fn choose_java_kotlin_tool(repo_root: &Path) -> ToolChoice<JavaKotlinTool> {
+    // Prefer Spotless if this looks like a Gradle project.
+    if has_gradle_project(repo_root) {
+        return ToolChoice {
+            tool: JavaKotlinTool::Spotless,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found gradlew/build.gradle/build.gradle.kts"),
+        };
+    }
+
+    if let Ok(pom) = std::fs::read_to_string(repo_root.join("pom.xml")) {
+        if pom.contains("spotless-maven-plugin") {
+            return ToolChoice {
+                tool: JavaKotlinTool::SpotlessMaven,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found spotless-maven-plugin in pom.xml"),
+            };
+        }
+        if pom.contains("fmt-maven-plugin") {
+            return ToolChoice {
+                tool: JavaKotlinTool::MavenFmt,
+                kind: ToolChoiceKind::Detected,
+                maybe_reason: Some("found fmt-maven-plugin in pom.xml"),
+            };
+        }
+        return ToolChoice {
+            tool: JavaKotlinTool::GoogleJavaFormat,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found pom.xml without a formatter plugin"),
+        };
+    }
+
+    if has_any_file_with_ext_bounded(repo_root, &["java"], 2, 10_000) {
+        return ToolChoice {
+            tool: JavaKotlinTool::GoogleJavaFormat,
+            kind: ToolChoiceKind::Detected,
+            maybe_reason: Some("found Java source files (shallow scan)"),
+        };
+    }
+
+    ToolChoice {
+        tool: JavaKotlinTool::Ktlint,
+        kind: ToolChoiceKind::Default,
+        maybe_reason: None,
+    }
+}
+
+/// Matches section headers like `[*.cs]` or `[*.{cs,vb}]`.
+fn editorconfig_has_csharp_section(contents: &str) -> bool {
+    contents
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix('[')?.strip_suffix(']'))
+        .any(|glob| {
+            glob.ends_with(".cs") || glob.split(['{', '}', ',']).any(|part| part.trim() == "cs")
+        })
+}
+
+fn has_prettier_config(repo_root: &Path) -> bool {
+    let prettier_configs = [
+        ".prettierrc",
+        ".prettierrc.json",
+        ".prettierrc.yaml",
+        ".prettierrc.yml",
+        ".prettierrc.js",
+        ".prettierrc.cjs",
+        "prettier.config.js",
+        "prettier.config.cjs",
+        "prettier.config.mjs",
+    ];
+    prettier_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+}
+
+fn has_dprint_config(repo_root: &Path) -> bool {
+    let dprint_configs = [
+        "dprint.json",
+        ".dprint.json",
+        "dprint.jsonc",
+        ".dprint.jsonc",
+    ];
+    dprint_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+}
+
+fn has_markdownlint_or_yamlfmt_config(repo_root: &Path) -> bool {
+    let configs = [
+        ".markdownlint-cli2.jsonc",
+        ".markdownlint-cli2.yaml",
+        ".markdownlint-cli2.cjs",
+        ".markdownlint.json",
+        ".markdownlint.jsonc",
+        ".markdownlint.yaml",
+        ".markdownlint.yml",
+        ".yamlfmt",
+        ".yamlfmt.yml",
+        ".yamlfmt.yaml",
+        "yamlfmt.yml",
+        "yamlfmt.yaml",
+    ];
+    configs.iter().any(|name| repo_root.join(name).is_file())
+}
+
+fn has_yamllint_config(repo_root: &Path) -> bool {
+    let configs = [".yamllint", ".yamllint.yml", ".yamllint.yaml"];
+    configs.iter().any(|name| repo_root.join(name).is_file())
+}
+
+fn has_prettier_or_eslint_config(repo_root: &Path) -> bool {
+    if has_prettier_config(repo_root) {
+        return true;
+    }
+
+    let eslint_configs = [
+        ".eslintrc",
+        ".eslintrc.json",
+        ".eslintrc.yaml",
+        ".eslintrc.yml",
+        ".eslintrc.js",
+        ".eslintrc.cjs",
+        "eslint.config.js",
+        "eslint.config.cjs",
+        "eslint.config.mjs",
+    ];
+    if eslint_configs
+        .iter()
+        .any(|name| repo_root.join(name).is_file())
+    {
+        return true;
+    }
+
+    // Heuristic: some repos store config in package.json.
+    let package_json = repo_root.join("package.json");
+    let Ok(contents) = std::fs::read_to_string(&package_json) else {
+        return false;
+    };
+
+    // Keep this heuristic intentionally simple: we're not parsing JSON, just looking for strong signals.
+    contents.contains("\"eslintConfig\"")
+        || contents.contains("\"prettier\"")
+        || contents.contains("\"eslint\"")
+}
+
+fn has_ruff_config(repo_root: &Path) -> bool {
+    if repo_root.join("ruff.toml").is_file() || repo_root.join(".ruff.toml").is_file() {
+        return true;
+    }
+
+    let pyproject = repo_root.join("pyproject.toml");
+    let Ok(contents) = std::fs::read_to_string(&pyproject) else {
+        return false;
+    };
+    contents.contains("[tool.ruff]")
+}
+
+fn has_black_config(repo_root: &Path) -> bool {
+    if repo_root.join("black.toml").is_file() {
+        return true;
+    }
+
+    let pyproject = repo_root.join("pyproject.toml");
+    let Ok(contents) = std::fs::read_to_string(&pyproject) else {
+        return false;
+    };
+    contents.contains("[tool.black]")
 }
 
 fn has_gradle_project(repo_root: &Path) -> bool {
@@ -394,299 +1135,788 @@ fn has_gradle_project(repo_root: &Path) -> bool {
         || repo_root.join("build.gradle.kts").is_file()
 }
 
-fn has_any_file_named_bounded(
-    repo_root: &Path,
-    names: &[&str],
-    max_dir_depth: usize,
-    max_entries: usize,
-) -> bool {
-    let mut visited_entries = 0usize;
-    let mut stack: Vec<(PathBuf, usize)> = vec![(repo_root.to_path_buf(), 0)];
+fn has_root_file_with_prefix(repo_root: &Path, prefixes: &[&str]) -> bool {
+    let Ok(entries) = std::fs::read_dir(repo_root) else {
+        return false;
+    };
+
+    entries.flatten().any(|entry| {
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else {
+            return false;
+        };
+        entry.path().is_file() && prefixes.iter().any(|prefix| name.starts_with(prefix))
+    })
+}
+
+/// Checks the repo root and its immediate subdirectories (e.g. `deploy/k8s`) for a named directory.
+fn has_dir_named_shallow(repo_root: &Path, names: &[&str]) -> bool {
+    if names.iter().any(|name| repo_root.join(name).is_dir()) {
+        return true;
+    }
+
+    let Ok(entries) = std::fs::read_dir(repo_root) else {
+        return false;
+    };
+
+    entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir() && entry.file_name() != ".git")
+        .any(|entry| names.iter().any(|name| entry.path().join(name).is_dir()))
+}
+
+fn has_any_file_named_bounded(
+    repo_root: &Path,
+    names: &[&str],
+    max_dir_depth: usize,
+    max_entries: usize,
+) -> bool {
+    has_any_file_named_bounded_where(repo_root, names, max_dir_depth, max_entries, |_| true)
+}
+
+/// Like `has_any_file_named_bounded`, but a matching file only counts if `accept` returns true.
+fn has_any_file_named_bounded_where(
+    repo_root: &Path,
+    names: &[&str],
+    max_dir_depth: usize,
+    max_entries: usize,
+    accept: impl Fn(&Path) -> bool,
+) -> bool {
+    let mut visited_entries = 0usize;
+    let mut stack: Vec<(PathBuf, usize)> = vec![(repo_root.to_path_buf(), 0)];
+
+    while let Some((dir, depth)) = stack.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries {
+            if visited_entries >= max_entries {
+                return false;
+            }
+            visited_entries += 1;
+
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+
+            let path = entry.path();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(_) => continue,
+            };
+
+            if file_type.is_file() {
+                let Some(name) = path.file_name().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                if names.contains(&name) && accept(&path) {
+                    return true;
+                }
+                continue;
+            }
+
+            if !file_type.is_dir() {
+                continue;
+            }
+
+            if depth >= max_dir_depth {
+                continue;
+            }
+
+            let Some(dir_name) = path.file_name().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            // Avoid scanning huge/unrelated directories.
+            if matches!(
+                dir_name,
+                ".git" | "node_modules" | "target" | "dist" | "build" | ".venv" | "__pycache__"
+            ) {
+                continue;
+            }
+
+            stack.push((path, depth + 1));
+        }
+    }
+
+    false
+}
+
+fn has_any_file_with_ext_bounded(
+    repo_root: &Path,
+    exts: &[&str],
+    max_dir_depth: usize,
+    max_entries: usize,
+) -> bool {
+    let mut visited_entries = 0usize;
+    let mut stack: Vec<(PathBuf, usize)> = vec![(repo_root.to_path_buf(), 0)];
+
+    while let Some((dir, depth)) = stack.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries {
+            if visited_entries >= max_entries {
+                return false;
+            }
+            visited_entries += 1;
+
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+
+            let path = entry.path();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(_) => continue,
+            };
+
+            if file_type.is_file() {
+                let Some(ext) = path.extension().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                if exts
+                    .iter()
+                    .any(|candidate| ext.eq_ignore_ascii_case(candidate))
+                {
+                    return true;
+                }
+                continue;
+            }
+
+            if !file_type.is_dir() {
+                continue;
+            }
+
+            if depth >= max_dir_depth {
+                continue;
+            }
+
+            let Some(name) = path.file_name().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            // Avoid scanning huge/unrelated directories.
+            if matches!(
+                name,
+                ".git" | "node_modules" | "target" | "dist" | "build" | ".venv" | "__pycache__"
+            ) {
+                continue;
+            }
+
+            stack.push((path, depth + 1));
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use tempfile::TempDir;
+
+    #[test]
+    fn choose_js_ts_tool_detects_biome_when_biome_config_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("biome.json"), "{ }")?;
+
+        // act
+        let choice = choose_js_ts_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, JsTsTool::Biome));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_js_ts_tool_detects_prettier_eslint_when_prettier_config_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join(".prettierrc"), "{}")?;
+
+        // act
+        let choice = choose_js_ts_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, JsTsTool::PrettierEslint));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_js_package_manager_prefers_package_manager_field_over_lockfiles() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(
+            temp.path().join("package.json"),
+            "{ \"name\": \"x\", \"packageManager\": \"pnpm@9.1.0\" }",
+        )?;
+        std::fs::write(temp.path().join("yarn.lock"), "")?;
+
+        // act
+        let choice = choose_js_package_manager(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, JsPackageManager::Pnpm));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_js_package_manager_detects_yarn_from_lockfile() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("package.json"), "{ }")?;
+        std::fs::write(temp.path().join("yarn.lock"), "")?;
+
+        // act
+        let choice = choose_js_package_manager(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, JsPackageManager::Yarn));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn detect_js_ts_repo_proof_none_when_no_signals_exist() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+
+        // act
+        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_js_ts_repo_proof_some_when_package_json_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("package.json"), "{ }")?;
+
+        // act
+        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_js_ts_repo_proof_some_when_nested_package_json_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        let packages_dir = temp.path().join("packages").join("app");
+        std::fs::create_dir_all(&packages_dir)?;
+        std::fs::write(packages_dir.join("package.json"), "{ }")?;
+
+        // act
+        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_python_repo_proof_some_when_pyproject_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("pyproject.toml"), "[build-system]\n")?;
+
+        // act
+        let maybe_reason = detect_python_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_go_repo_proof_some_when_go_mod_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("go.mod"), "module example.com/x\n")?;
+
+        // act
+        let maybe_reason = detect_go_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_ruby_repo_proof_some_when_gemfile_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(
+            temp.path().join("Gemfile"),
+            "source 'https://rubygems.org'\n",
+        )?;
+
+        // act
+        let maybe_reason = detect_ruby_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn detect_typescript_repo_proof_some_when_tsconfig_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("tsconfig.json"), "{ }")?;
+
+        // act
+        let maybe_reason = detect_typescript_repo_proof(temp.path());
+
+        // assert
+        assert!(maybe_reason.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn choose_python_tool_detects_ruff_when_pyproject_has_tool_ruff() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(
+            temp.path().join("pyproject.toml"),
+            "[tool.ruff]\nline-length = 88\n",
+        )?;
+
+        // act
+        let choice = choose_python_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, PythonTool::Ruff));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_python_runner_detects_uv_when_uv_lock_exists() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("uv.lock"), "version = 1\n")?;
+        std::fs::create_dir_all(temp.path().join(".venv"))?;
+
+        // act
+        let choice = choose_python_runner(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, PythonRunner::Uv));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_python_typecheck_tool_detects_pyright_config() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("pyrightconfig.json"), "{ }")?;
+
+        // act
+        let maybe_choice = choose_python_typecheck_tool(temp.path());
+
+        // assert
+        assert!(matches!(
+            maybe_choice.map(|choice| choice.tool),
+            Some(PythonTypecheckTool::Pyright)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn choose_python_typecheck_tool_none_without_config() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("pyproject.toml"), "[tool.ruff]\n")?;
+
+        // act
+        let maybe_choice = choose_python_typecheck_tool(temp.path());
+
+        // assert
+        assert!(maybe_choice.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn choose_java_kotlin_tool_detects_spotless_maven_plugin() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(
+            temp.path().join("pom.xml"),
+            "<project><build><plugins><plugin>\n\
+             <artifactId>spotless-maven-plugin</artifactId>\n\
+             </plugin></plugins></build></project>\n",
+        )?;
+
+        // act
+        let choice = choose_java_kotlin_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, JavaKotlinTool::SpotlessMaven));
+        Ok(())
+    }
 
-    while let Some((dir, depth)) = stack.pop() {
-        let entries = match std::fs::read_dir(&dir) {
-            Ok(entries) => entries,
-            Err(_) => continue,
-        };
+    #[test]
+    fn choose_java_kotlin_tool_uses_google_java_format_for_plain_pom() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("pom.xml"), "<project></project>\n")?;
 
-        for entry in entries {
-            if visited_entries >= max_entries {
-                return false;
-            }
-            visited_entries += 1;
+        // act
+        let choice = choose_java_kotlin_tool(temp.path());
 
-            let entry = match entry {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
+        // assert
+        assert!(matches!(choice.tool, JavaKotlinTool::GoogleJavaFormat));
+        Ok(())
+    }
 
-            let path = entry.path();
-            let file_type = match entry.file_type() {
-                Ok(file_type) => file_type,
-                Err(_) => continue,
-            };
+    #[test]
+    fn detect_md_yaml_repo_proof_finds_yaml_but_not_a_lone_readme() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("README.md"), "# x\n")?;
+        let readme_only = detect_md_yaml_repo_proof(temp.path());
+        std::fs::create_dir_all(temp.path().join("config"))?;
+        std::fs::write(temp.path().join("config").join("app.yml"), "a: 1\n")?;
 
-            if file_type.is_file() {
-                let Some(name) = path.file_name().and_then(|s| s.to_str()) else {
-                    continue;
-                };
-                if names.contains(&name) {
-                    return true;
-                }
-                continue;
-            }
+        // act
+        let maybe_proof = detect_md_yaml_repo_proof(temp.path());
 
-            if !file_type.is_dir() {
-                continue;
-            }
+        // assert
+        assert!(readme_only.is_none());
+        assert!(maybe_proof.is_some());
+        assert!(detect_js_ts_repo_proof(temp.path()).is_none());
+        Ok(())
+    }
 
-            if depth >= max_dir_depth {
-                continue;
-            }
+    #[test]
+    fn detect_md_yaml_repo_proof_finds_markdown_in_prettier_and_js_repos() -> Result<()> {
+        // arrange
+        let prettier_repo = TempDir::new()?;
+        std::fs::write(prettier_repo.path().join("README.md"), "# x\n")?;
+        std::fs::write(prettier_repo.path().join(".prettierrc"), "{}")?;
+        let js_repo = TempDir::new()?;
+        std::fs::write(js_repo.path().join("README.md"), "# x\n")?;
+        std::fs::write(js_repo.path().join("package.json"), "{}")?;
 
-            let Some(dir_name) = path.file_name().and_then(|s| s.to_str()) else {
-                continue;
-            };
+        // act
+        let maybe_prettier_proof = detect_md_yaml_repo_proof(prettier_repo.path());
+        let maybe_js_proof = detect_md_yaml_repo_proof(js_repo.path());
 
-            // Avoid scanning huge/unrelated directories.
-            if matches!(
-                dir_name,
-                ".git" | "node_modules" | "target" | "dist" | "build" | ".venv" | "__pycache__"
-            ) {
-                continue;
-            }
+        // assert
+        assert!(maybe_prettier_proof.is_some());
+        assert!(maybe_js_proof.is_some());
+        assert!(matches!(
+            choose_md_yaml_tool(js_repo.path()).tool,
+            MdYamlTool::Prettier
+        ));
+        Ok(())
+    }
 
-            stack.push((path, depth + 1));
-        }
+    #[test]
+    fn choose_md_yaml_tool_detects_dprint_over_prettier() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("dprint.json"), "{}")?;
+        std::fs::write(temp.path().join(".prettierrc"), "{}")?;
+
+        // act
+        let choice = choose_md_yaml_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, MdYamlTool::Dprint));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
     }
 
-    false
-}
+    #[test]
+    fn choose_md_yaml_tool_defaults_to_markdownlint_yamlfmt_without_package_json() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join(".yamllint"), "extends: default\n")?;
 
-fn has_any_file_with_ext_bounded(
-    repo_root: &Path,
-    exts: &[&str],
-    max_dir_depth: usize,
-    max_entries: usize,
-) -> bool {
-    let mut visited_entries = 0usize;
-    let mut stack: Vec<(PathBuf, usize)> = vec![(repo_root.to_path_buf(), 0)];
+        // act
+        let choice = choose_md_yaml_tool(temp.path());
 
-    while let Some((dir, depth)) = stack.pop() {
-        let entries = match std::fs::read_dir(&dir) {
-            Ok(entries) => entries,
-            Err(_) => continue,
-        };
+        // assert
+        assert!(matches!(choice.tool, MdYamlTool::MarkdownlintYamlfmt));
+        assert_eq!(choice.kind, ToolChoiceKind::Default);
+        assert!(detect_yamllint_config(temp.path()).is_some());
+        Ok(())
+    }
 
-        for entry in entries {
-            if visited_entries >= max_entries {
-                return false;
-            }
-            visited_entries += 1;
+    #[test]
+    fn detect_config_files_repo_proof_finds_nested_toml() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::create_dir_all(temp.path().join("config"))?;
+        std::fs::write(temp.path().join("config").join("app.toml"), "a = 1\n")?;
 
-            let entry = match entry {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
+        // act
+        let maybe_proof = detect_config_files_repo_proof(temp.path());
 
-            let path = entry.path();
-            let file_type = match entry.file_type() {
-                Ok(file_type) => file_type,
-                Err(_) => continue,
-            };
+        // assert
+        assert!(maybe_proof.is_some());
+        Ok(())
+    }
 
-            if file_type.is_file() {
-                let Some(ext) = path.extension().and_then(|s| s.to_str()) else {
-                    continue;
-                };
-                if exts
-                    .iter()
-                    .any(|candidate| ext.eq_ignore_ascii_case(candidate))
-                {
-                    return true;
-                }
-                continue;
-            }
+    #[test]
+    fn detect_dockerfile_repo_proof_finds_suffixed_dockerfile() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("Dockerfile.dev"), "FROM scratch\n")?;
 
-            if !file_type.is_dir() {
-                continue;
-            }
+        // act
+        let maybe_proof = detect_dockerfile_repo_proof(temp.path());
 
-            if depth >= max_dir_depth {
-                continue;
-            }
+        // assert
+        assert!(maybe_proof.is_some());
+        Ok(())
+    }
 
-            let Some(name) = path.file_name().and_then(|s| s.to_str()) else {
-                continue;
-            };
+    #[test]
+    fn detect_kubernetes_repo_proof_finds_nested_k8s_dir() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::create_dir_all(temp.path().join("deploy").join("k8s"))?;
 
-            // Avoid scanning huge/unrelated directories.
-            if matches!(
-                name,
-                ".git" | "node_modules" | "target" | "dist" | "build" | ".venv" | "__pycache__"
-            ) {
-                continue;
-            }
+        // act
+        let maybe_proof = detect_kubernetes_repo_proof(temp.path());
 
-            stack.push((path, depth + 1));
-        }
+        // assert
+        assert!(maybe_proof.is_some());
+        assert!(detect_github_actions_repo_proof(temp.path()).is_none());
+        Ok(())
     }
 
-    false
-}
+    #[test]
+    fn choose_terraform_tool_detects_opentofu_lockfile() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(
+            temp.path().join(".terraform.lock.hcl"),
+            "provider \"registry.opentofu.org/hashicorp/aws\" {\n  version = \"5.0.0\"\n}\n",
+        )?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use anyhow::Result;
-    use tempfile::TempDir;
+        // act
+        let choice = choose_terraform_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, TerraformTool::OpenTofu));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        Ok(())
+    }
 
     #[test]
-    fn choose_js_ts_tool_detects_biome_when_biome_config_exists() -> Result<()> {
+    fn choose_terraform_tool_detects_nested_opentofu_lockfile() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join("biome.json"), "{ }")?;
+        let module_dir = temp.path().join("infra").join("network");
+        std::fs::create_dir_all(&module_dir)?;
+        std::fs::write(
+            module_dir.join(".terraform.lock.hcl"),
+            "provider \"registry.opentofu.org/hashicorp/aws\" {\n  version = \"5.0.0\"\n}\n",
+        )?;
 
         // act
-        let choice = choose_js_ts_tool(temp.path());
+        let choice = choose_terraform_tool(temp.path());
 
         // assert
-        assert!(matches!(choice.tool, JsTsTool::Biome));
+        assert!(matches!(choice.tool, TerraformTool::OpenTofu));
         assert_eq!(choice.kind, ToolChoiceKind::Detected);
         Ok(())
     }
 
     #[test]
-    fn choose_js_ts_tool_detects_prettier_eslint_when_prettier_config_exists() -> Result<()> {
+    fn choose_cmake_tool_detects_gersemi_config() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join(".prettierrc"), "{}")?;
+        std::fs::write(temp.path().join("CMakeLists.txt"), "project(x)\n")?;
+        std::fs::write(temp.path().join(".gersemirc"), "line_length: 100\n")?;
 
         // act
-        let choice = choose_js_ts_tool(temp.path());
+        let choice = choose_cmake_tool(temp.path());
 
         // assert
-        assert!(matches!(choice.tool, JsTsTool::PrettierEslint));
+        assert!(matches!(choice.tool, CmakeTool::Gersemi));
         assert_eq!(choice.kind, ToolChoiceKind::Detected);
+        assert!(detect_c_cpp_repo_proof(temp.path()).is_some());
         Ok(())
     }
 
     #[test]
-    fn detect_js_ts_repo_proof_none_when_no_signals_exist() -> Result<()> {
+    fn detect_clang_tidy_proof_requires_a_clang_tidy_config() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
+        std::fs::create_dir_all(temp.path().join("build"))?;
+        std::fs::write(
+            temp.path().join("build").join("compile_commands.json"),
+            "[]",
+        )?;
+        let database_only = detect_clang_tidy_proof(temp.path());
+        std::fs::write(temp.path().join(".clang-tidy"), "Checks: 'bugprone-*'\n")?;
 
         // act
-        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+        let maybe_proof = detect_clang_tidy_proof(temp.path());
 
         // assert
-        assert!(maybe_reason.is_none());
+        assert!(database_only.is_none());
+        assert!(maybe_proof.is_some());
         Ok(())
     }
 
     #[test]
-    fn detect_js_ts_repo_proof_some_when_package_json_exists() -> Result<()> {
+    fn choose_ruby_tool_detects_standard_from_gemfile_lock() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join("package.json"), "{ }")?;
+        std::fs::write(
+            temp.path().join("Gemfile.lock"),
+            "GEM\n  specs:\n    rubocop (1.60.2)\n    standard (1.33.0)\n      rubocop (~> 1.60)\n",
+        )?;
 
         // act
-        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+        let choice = choose_ruby_tool(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert!(matches!(choice.tool, RubyTool::Standard));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
         Ok(())
     }
 
     #[test]
-    fn detect_js_ts_repo_proof_some_when_nested_package_json_exists() -> Result<()> {
+    fn detect_swift_repo_proof_finds_nested_xcode_project() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        let packages_dir = temp.path().join("packages").join("app");
-        std::fs::create_dir_all(&packages_dir)?;
-        std::fs::write(packages_dir.join("package.json"), "{ }")?;
+        let xcodeproj = temp.path().join("ios").join("App.xcodeproj");
+        std::fs::create_dir_all(&xcodeproj)?;
+        std::fs::write(xcodeproj.join("project.pbxproj"), "// !$*UTF8*$!\n")?;
 
         // act
-        let maybe_reason = detect_js_ts_repo_proof(temp.path());
+        let maybe_proof = detect_swift_repo_proof(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert!(maybe_proof.is_some());
         Ok(())
     }
 
     #[test]
-    fn detect_python_repo_proof_some_when_pyproject_exists() -> Result<()> {
+    fn choose_swift_tool_detects_swiftlint_config() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join("pyproject.toml"), "[build-system]\n")?;
+        std::fs::write(temp.path().join(".swiftlint.yml"), "disabled_rules: []\n")?;
 
         // act
-        let maybe_reason = detect_python_repo_proof(temp.path());
+        let choice = choose_swift_tool(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert!(matches!(choice.tool, SwiftTool::Swiftlint));
         Ok(())
     }
 
     #[test]
-    fn detect_go_repo_proof_some_when_go_mod_exists() -> Result<()> {
+    fn choose_php_tool_detects_pint_from_composer_json() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join("go.mod"), "module example.com/x\n")?;
+        std::fs::write(
+            temp.path().join("composer.json"),
+            r#"{ "require-dev": { "laravel/pint": "^1.13" } }"#,
+        )?;
 
         // act
-        let maybe_reason = detect_go_repo_proof(temp.path());
+        let choice = choose_php_tool(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert!(matches!(choice.tool, PhpTool::Pint));
+        assert_eq!(choice.kind, ToolChoiceKind::Detected);
         Ok(())
     }
 
     #[test]
-    fn detect_ruby_repo_proof_some_when_gemfile_exists() -> Result<()> {
+    fn choose_php_tool_detects_phpcs_config() -> Result<()> {
+        // arrange
+        let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("phpcs.xml"), "<ruleset name=\"app\"/>\n")?;
+
+        // act
+        let choice = choose_php_tool(temp.path());
+
+        // assert
+        assert!(matches!(choice.tool, PhpTool::Phpcbf));
+        Ok(())
+    }
+
+    #[test]
+    fn detect_csharp_repo_proof_finds_editorconfig_section() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
         std::fs::write(
-            temp.path().join("Gemfile"),
-            "source 'https://rubygems.org'\n",
+            temp.path().join(".editorconfig"),
+            "root = true\n\n[*.{cs,vb}]\ndotnet_sort_system_directives_first = true\n",
         )?;
 
         // act
-        let maybe_reason = detect_ruby_repo_proof(temp.path());
+        let maybe_proof = detect_csharp_repo_proof(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert_eq!(maybe_proof, Some("found C# section in .editorconfig"));
         Ok(())
     }
 
     #[test]
-    fn detect_typescript_repo_proof_some_when_tsconfig_exists() -> Result<()> {
+    fn detect_elixir_repo_proof_finds_umbrella_app() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
-        std::fs::write(temp.path().join("tsconfig.json"), "{ }")?;
+        let app_dir = temp.path().join("apps").join("web");
+        std::fs::create_dir_all(&app_dir)?;
+        std::fs::write(
+            app_dir.join("mix.exs"),
+            "defmodule Web.MixProject do\nend\n",
+        )?;
 
         // act
-        let maybe_reason = detect_typescript_repo_proof(temp.path());
+        let maybe_proof = detect_elixir_repo_proof(temp.path());
 
         // assert
-        assert!(maybe_reason.is_some());
+        assert_eq!(
+            maybe_proof,
+            Some("found nested Elixir project file (shallow scan)")
+        );
         Ok(())
     }
 
     #[test]
-    fn choose_python_tool_detects_ruff_when_pyproject_has_tool_ruff() -> Result<()> {
+    fn choose_go_tool_detects_golangci_lint_config() -> Result<()> {
         // arrange
         let temp = TempDir::new()?;
+        std::fs::write(temp.path().join("go.mod"), "module example.com/demo\n")?;
         std::fs::write(
-            temp.path().join("pyproject.toml"),
-            "[tool.ruff]\nline-length = 88\n",
+            temp.path().join(".golangci.yml"),
+            "linters:\n  enable: [gofmt]\n",
         )?;
 
         // act
-        let choice = choose_python_tool(temp.path());
+        let choice = choose_go_tool(temp.path());
 
         // assert
-        assert!(matches!(choice.tool, PythonTool::Ruff));
+        assert!(matches!(choice.tool, GoTool::GolangciLint));
         assert_eq!(choice.kind, ToolChoiceKind::Detected);
         Ok(())
     }
@@ -3,15 +3,74 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 
 use crate::cargo_repo::ResolveHookOptions;
-use crate::hooks::{JavaKotlinTool, JsTsTool, ManagedPreCommitSettings, PythonTool};
+use crate::hooks::{
+    CmakeTool, GoTool, HygieneChecks, JavaKotlinTool, JsPackageManager, JsTsTool, KubernetesTool,
+    ManagedPreCommitSettings, MdYamlTool, PhpTool, PythonRunner, PythonTool, PythonTypecheckTool,
+    RubyTool, StageTimeout, StageTimeoutPolicy, SwiftTool, TerraformTool,
+};
+use crate::util::relative_display;
 
 use super::detect::{
-    choose_java_kotlin_tool, choose_js_ts_tool, choose_python_tool, detect_c_cpp_repo_proof,
-    detect_go_repo_proof, detect_java_kotlin_repo_proof, detect_js_ts_repo_proof,
-    detect_python_repo_proof, detect_ruby_repo_proof, detect_shell_repo_proof,
-    detect_terraform_repo_proof, detect_typescript_repo_proof, ToolChoice, ToolChoiceKind,
+    choose_cmake_tool, choose_go_tool, choose_java_kotlin_tool, choose_js_package_manager,
+    choose_js_ts_tool, choose_kubernetes_tool, choose_md_yaml_tool, choose_php_tool,
+    choose_python_runner, choose_python_tool, choose_python_typecheck_tool, choose_ruby_tool,
+    choose_swift_tool, choose_terraform_tool, detect_c_cpp_repo_proof, detect_clang_tidy_proof,
+    detect_cmake_repo_proof, detect_config_files_repo_proof, detect_csharp_repo_proof,
+    detect_dart_repo_proof, detect_dockerfile_repo_proof, detect_elixir_repo_proof,
+    detect_github_actions_repo_proof, detect_go_repo_proof, detect_java_kotlin_repo_proof,
+    detect_js_ts_repo_proof, detect_kubernetes_repo_proof, detect_md_yaml_repo_proof,
+    detect_php_repo_proof, detect_python_repo_proof, detect_ruby_repo_proof,
+    detect_shell_repo_proof, detect_swift_repo_proof, detect_terraform_repo_proof,
+    detect_tflint_config, detect_typescript_repo_proof, detect_yamllint_config,
+    detect_zig_repo_proof, ToolChoice, ToolChoiceKind,
 };
 
+// Stages that may download packages (JS package managers) or wait on a build daemon (Gradle) can
+// hang on a flaky network; bound them and skip with a warning rather than blocking the commit.
+const JS_TS_STAGE_TIMEOUT_SECONDS: u32 = 300;
+const JAVA_KOTLIN_STAGE_TIMEOUT_SECONDS: u32 = 600;
+// Vendored C/C++ code keeps its upstream formatting.
+const DEFAULT_C_CPP_EXCLUDED_DIRS: [&str; 6] = [
+    "third_party",
+    "third-party",
+    "3rdparty",
+    "vendor",
+    "external",
+    "extern",
+];
+// kubeconform downloads schemas on first use.
+const KUBERNETES_STAGE_TIMEOUT_SECONDS: u32 = 300;
+
+fn default_stage_timeout_overrides(
+    js_ts_enabled: bool,
+    java_kotlin_enabled: bool,
+    kubernetes_enabled: bool,
+) -> Vec<StageTimeout> {
+    let mut overrides = Vec::new();
+    if js_ts_enabled {
+        overrides.push(StageTimeout {
+            stage: "js_ts",
+            seconds: JS_TS_STAGE_TIMEOUT_SECONDS,
+            policy: StageTimeoutPolicy::Skip,
+        });
+    }
+    if java_kotlin_enabled {
+        overrides.push(StageTimeout {
+            stage: "java_kotlin",
+            seconds: JAVA_KOTLIN_STAGE_TIMEOUT_SECONDS,
+            policy: StageTimeoutPolicy::Skip,
+        });
+    }
+    if kubernetes_enabled {
+        overrides.push(StageTimeout {
+            stage: "kubernetes",
+            seconds: KUBERNETES_STAGE_TIMEOUT_SECONDS,
+            policy: StageTimeoutPolicy::Skip,
+        });
+    }
+    overrides
+}
+
 fn print_tool_choice<T: Copy>(label: &str, choice: ToolChoice<T>, tool_display: &str) {
     match choice.kind {
         ToolChoiceKind::Detected => {
@@ -29,21 +88,47 @@ fn print_tool_choice<T: Copy>(label: &str, choice: ToolChoice<T>, tool_display:
 
 pub fn resolve_pre_commit_settings(
     repo_root: &Path,
-    maybe_cargo_dir: Option<PathBuf>,
+    cargo_dirs: Vec<PathBuf>,
     options: ResolveHookOptions,
 ) -> Result<ManagedPreCommitSettings> {
     let maybe_js_ts_proof = detect_js_ts_repo_proof(repo_root);
     let maybe_ts_proof = detect_typescript_repo_proof(repo_root);
+    let maybe_md_yaml_proof = detect_md_yaml_repo_proof(repo_root);
+    let maybe_yamllint_proof = detect_yamllint_config(repo_root);
+    let maybe_config_files_proof = detect_config_files_repo_proof(repo_root);
     let maybe_python_proof = detect_python_repo_proof(repo_root);
     let maybe_java_kotlin_proof = detect_java_kotlin_repo_proof(repo_root);
     let maybe_go_proof = detect_go_repo_proof(repo_root);
     let maybe_shell_proof = detect_shell_repo_proof(repo_root);
     let maybe_terraform_proof = detect_terraform_repo_proof(repo_root);
+    let maybe_tflint_proof = detect_tflint_config(repo_root);
     let maybe_c_cpp_proof = detect_c_cpp_repo_proof(repo_root);
+    let maybe_clang_tidy_proof = detect_clang_tidy_proof(repo_root);
+    let maybe_cmake_proof = detect_cmake_repo_proof(repo_root);
     let maybe_ruby_proof = detect_ruby_repo_proof(repo_root);
+    let maybe_swift_proof = detect_swift_repo_proof(repo_root);
+    let maybe_php_proof = detect_php_repo_proof(repo_root);
+    let maybe_csharp_proof = detect_csharp_repo_proof(repo_root);
+    let maybe_dart_proof = detect_dart_repo_proof(repo_root);
+    let maybe_elixir_proof = detect_elixir_repo_proof(repo_root);
+    let maybe_zig_proof = detect_zig_repo_proof(repo_root);
+    let maybe_dockerfile_proof = detect_dockerfile_repo_proof(repo_root);
+    let maybe_github_actions_proof = detect_github_actions_repo_proof(repo_root);
+    let maybe_kubernetes_proof = detect_kubernetes_repo_proof(repo_root);
     let js_ts_choice = choose_js_ts_tool(repo_root);
+    let js_package_manager_choice = choose_js_package_manager(repo_root);
+    let md_yaml_choice = choose_md_yaml_tool(repo_root);
     let python_choice = choose_python_tool(repo_root);
+    let python_runner_choice = choose_python_runner(repo_root);
+    let maybe_python_typecheck_choice = choose_python_typecheck_tool(repo_root);
     let java_kotlin_choice = choose_java_kotlin_tool(repo_root);
+    let go_choice = choose_go_tool(repo_root);
+    let swift_choice = choose_swift_tool(repo_root);
+    let php_choice = choose_php_tool(repo_root);
+    let ruby_choice = choose_ruby_tool(repo_root);
+    let cmake_choice = choose_cmake_tool(repo_root);
+    let terraform_choice = choose_terraform_tool(repo_root);
+    let kubernetes_choice = choose_kubernetes_tool(repo_root);
 
     if !options.non_interactive {
         if let Some(reason) = maybe_js_ts_proof {
@@ -53,6 +138,17 @@ pub fn resolve_pre_commit_settings(
             };
             println!("Detected JS/TS repo signals ({reason})");
             print_tool_choice("JS/TS toolchain", js_ts_choice, js_ts_display);
+            let js_package_manager_display = match js_package_manager_choice.tool {
+                JsPackageManager::Npm => "npm",
+                JsPackageManager::Pnpm => "pnpm",
+                JsPackageManager::Yarn => "yarn",
+                JsPackageManager::Bun => "bun",
+            };
+            print_tool_choice(
+                "JS package manager",
+                js_package_manager_choice,
+                js_package_manager_display,
+            );
             if let Some(reason) = maybe_ts_proof {
                 println!("Enabling TypeScript typecheck (detected signals: {reason})");
             } else {
@@ -62,18 +158,66 @@ pub fn resolve_pre_commit_settings(
             println!("Skipping JS/TS toolchain (no JS/TS repo signals found)");
             println!("Disabling TypeScript typecheck (JS/TS toolchain not enabled)");
         }
+
+        if let Some(reason) = maybe_md_yaml_proof {
+            let md_yaml_display = match md_yaml_choice.tool {
+                MdYamlTool::Prettier => "prettier",
+                MdYamlTool::Dprint => "dprint",
+                MdYamlTool::MarkdownlintYamlfmt => {
+                    "markdownlint-cli2 --fix (with a .markdownlint config) + yamlfmt"
+                }
+            };
+            println!("Detected Markdown/YAML repo signals ({reason})");
+            print_tool_choice("Markdown/YAML toolchain", md_yaml_choice, md_yaml_display);
+            if let Some(reason) = maybe_yamllint_proof {
+                println!("Enabling yamllint (detected signals: {reason})");
+            } else {
+                println!("Disabling yamllint (no yamllint config found)");
+            }
+        } else {
+            println!("Skipping Markdown/YAML toolchain (no Markdown/YAML repo signals found)");
+        }
+
+        if let Some(reason) = maybe_config_files_proof {
+            println!(
+                "Enabling TOML/JSON config formatting and validation (detected signals: {reason})"
+            );
+        } else {
+            println!("Disabling TOML/JSON config checks (no TOML/JSON files found)");
+        }
         let python_display = match python_choice.tool {
             PythonTool::Ruff => "ruff",
             PythonTool::Black => "black",
         };
         let java_kotlin_display = match java_kotlin_choice.tool {
-            JavaKotlinTool::Spotless => "spotless",
+            JavaKotlinTool::Spotless => "spotless (gradle)",
+            JavaKotlinTool::SpotlessMaven => "spotless (maven)",
+            JavaKotlinTool::MavenFmt => "fmt-maven-plugin",
+            JavaKotlinTool::GoogleJavaFormat => "google-java-format",
             JavaKotlinTool::Ktlint => "ktlint",
         };
 
         if let Some(reason) = maybe_python_proof {
             println!("Detected Python repo signals ({reason})");
             print_tool_choice("Python toolchain", python_choice, python_display);
+            let python_runner_display = match python_runner_choice.tool {
+                PythonRunner::Path => "PATH",
+                PythonRunner::Venv => ".venv/bin",
+                PythonRunner::Uv => "uv run",
+                PythonRunner::Poetry => "poetry run",
+                PythonRunner::Pipenv => "pipenv run",
+                PythonRunner::Hatch => "hatch run",
+            };
+            print_tool_choice("Python runner", python_runner_choice, python_runner_display);
+            if let Some(typecheck_choice) = maybe_python_typecheck_choice {
+                let typecheck_display = match typecheck_choice.tool {
+                    PythonTypecheckTool::Mypy => "mypy",
+                    PythonTypecheckTool::Pyright => "pyright",
+                };
+                print_tool_choice("Python type checker", typecheck_choice, typecheck_display);
+            } else {
+                println!("Disabling Python type check (no mypy/pyright config found)");
+            }
         } else {
             println!("Skipping Python toolchain (no Python repo signals found)");
         }
@@ -90,9 +234,14 @@ pub fn resolve_pre_commit_settings(
         }
 
         if let Some(reason) = maybe_go_proof {
-            println!("Enabling Go formatting (detected signals: {reason})");
+            let go_display = match go_choice.tool {
+                GoTool::Goimports => "goimports (gofmt fallback)",
+                GoTool::GolangciLint => "golangci-lint",
+            };
+            println!("Detected Go repo signals ({reason})");
+            print_tool_choice("Go toolchain", go_choice, go_display);
         } else {
-            println!("Disabling Go formatting (no Go repo signals found)");
+            println!("Skipping Go toolchain (no Go repo signals found)");
         }
 
         if let Some(reason) = maybe_shell_proof {
@@ -102,21 +251,151 @@ pub fn resolve_pre_commit_settings(
         }
 
         if let Some(reason) = maybe_terraform_proof {
-            println!("Enabling Terraform formatting (detected signals: {reason})");
+            let terraform_display = match terraform_choice.tool {
+                TerraformTool::Terraform => "terraform",
+                TerraformTool::OpenTofu => "tofu (OpenTofu)",
+            };
+            println!("Detected Terraform repo signals ({reason})");
+            print_tool_choice("Terraform binary", terraform_choice, terraform_display);
+            if let Some(reason) = maybe_tflint_proof {
+                println!("Enabling tflint (detected signals: {reason})");
+            } else {
+                println!("Disabling tflint (no .tflint.hcl found)");
+            }
         } else {
-            println!("Disabling Terraform formatting (no Terraform repo signals found)");
+            println!("Skipping Terraform toolchain (no Terraform repo signals found)");
         }
 
         if let Some(reason) = maybe_c_cpp_proof {
             println!("Enabling C/C++ formatting (detected signals: {reason})");
+            println!(
+                "Skipping vendored C/C++ directories ({})",
+                DEFAULT_C_CPP_EXCLUDED_DIRS.join(", ")
+            );
+            if let Some(reason) = maybe_clang_tidy_proof {
+                println!("Enabling clang-tidy --fix (detected signals: {reason})");
+            } else {
+                println!("Disabling clang-tidy (no .clang-tidy found)");
+            }
+            if let Some(reason) = maybe_cmake_proof {
+                let cmake_display = match cmake_choice.tool {
+                    CmakeTool::CmakeFormat => "cmake-format",
+                    CmakeTool::Gersemi => "gersemi",
+                };
+                println!("Detected CMake signals ({reason})");
+                print_tool_choice("CMake formatter", cmake_choice, cmake_display);
+            } else {
+                println!("Skipping CMake formatting (no CMakeLists.txt found)");
+            }
         } else {
             println!("Disabling C/C++ formatting (no C/C++ repo signals found)");
         }
 
         if let Some(reason) = maybe_ruby_proof {
-            println!("Enabling Ruby formatting (detected signals: {reason})");
+            let ruby_display = match ruby_choice.tool {
+                RubyTool::Rubocop => "rubocop",
+                RubyTool::Standard => "standardrb",
+            };
+            println!("Detected Ruby repo signals ({reason})");
+            print_tool_choice("Ruby toolchain", ruby_choice, ruby_display);
         } else {
-            println!("Disabling Ruby formatting (no Ruby repo signals found)");
+            println!("Skipping Ruby toolchain (no Ruby repo signals found)");
+        }
+
+        if let Some(reason) = maybe_swift_proof {
+            let swift_display = match swift_choice.tool {
+                SwiftTool::SwiftFormat => "swift-format",
+                SwiftTool::Swiftlint => "swiftlint --fix",
+            };
+            println!("Detected Swift repo signals ({reason})");
+            print_tool_choice("Swift toolchain", swift_choice, swift_display);
+        } else {
+            println!("Skipping Swift toolchain (no Swift repo signals found)");
+        }
+
+        if let Some(reason) = maybe_php_proof {
+            let php_display = match php_choice.tool {
+                PhpTool::PhpCsFixer => "php-cs-fixer",
+                PhpTool::Phpcbf => "phpcbf (PHP_CodeSniffer)",
+                PhpTool::Pint => "pint",
+            };
+            println!("Detected PHP repo signals ({reason})");
+            print_tool_choice("PHP toolchain", php_choice, php_display);
+        } else {
+            println!("Skipping PHP toolchain (no PHP repo signals found)");
+        }
+
+        if let Some(reason) = maybe_csharp_proof {
+            println!("Enabling C# formatting via dotnet format (detected signals: {reason})");
+        } else {
+            println!("Disabling C# formatting (no .NET repo signals found)");
+        }
+
+        if let Some(reason) = maybe_dart_proof {
+            println!("Enabling Dart formatting (detected signals: {reason})");
+        } else {
+            println!("Disabling Dart formatting (no Dart/Flutter repo signals found)");
+        }
+
+        if let Some(reason) = maybe_elixir_proof {
+            println!("Enabling Elixir formatting (detected signals: {reason})");
+        } else {
+            println!("Disabling Elixir formatting (no Elixir repo signals found)");
+        }
+
+        if let Some(reason) = maybe_zig_proof {
+            println!("Enabling Zig formatting (detected signals: {reason})");
+        } else {
+            println!("Disabling Zig formatting (no Zig repo signals found)");
+        }
+
+        if let Some(reason) = maybe_dockerfile_proof {
+            println!("Enabling Dockerfile lint via hadolint (detected signals: {reason})");
+        } else {
+            println!("Disabling Dockerfile lint (no Dockerfile/Containerfile found)");
+        }
+
+        if let Some(reason) = maybe_github_actions_proof {
+            println!("Enabling GitHub Actions lint via actionlint (detected signals: {reason})");
+        } else {
+            println!("Disabling GitHub Actions lint (no .github/workflows found)");
+        }
+
+        if let Some(reason) = maybe_kubernetes_proof {
+            let kubernetes_display = match kubernetes_choice.tool {
+                KubernetesTool::Kubeconform => "kubeconform",
+                KubernetesTool::KubeLinter => "kube-linter",
+            };
+            println!("Detected Kubernetes manifest signals ({reason})");
+            print_tool_choice(
+                "Kubernetes manifest check",
+                kubernetes_choice,
+                kubernetes_display,
+            );
+        } else {
+            println!("Skipping Kubernetes manifest check (no k8s/ or kubernetes/ directory found)");
+        }
+
+        println!(
+            "Enabling secrets scan (gitleaks/trufflehog when installed, otherwise built-in patterns)"
+        );
+
+        println!(
+            "Enabling hygiene checks (conflict markers, large files, .env/private keys, line \
+             endings, final newline, trailing whitespace)"
+        );
+
+        if cargo_dirs.is_empty() {
+            println!("Disabling Rust formatting (no Cargo manifest dir resolved)");
+        } else {
+            let labels: Vec<String> = cargo_dirs
+                .iter()
+                .map(|dir| relative_display(repo_root, dir))
+                .collect();
+            println!(
+                "Enabling Rust formatting (Cargo manifest dirs: {})",
+                labels.join(", ")
+            );
         }
     }
 
@@ -132,14 +411,57 @@ pub fn resolve_pre_commit_settings(
     Ok(ManagedPreCommitSettings {
         enabled: true,
         maybe_js_ts_tool: maybe_js_ts_proof.map(|_| js_ts_choice.tool),
+        js_package_manager: js_package_manager_choice.tool,
+        js_npx_fallback_enabled: false,
         ts_typecheck_enabled: maybe_js_ts_proof.is_some() && maybe_ts_proof.is_some(),
+        maybe_md_yaml_tool: maybe_md_yaml_proof.map(|_| md_yaml_choice.tool),
+        yamllint_enabled: maybe_md_yaml_proof.is_some() && maybe_yamllint_proof.is_some(),
+        config_files_enabled: maybe_config_files_proof.is_some(),
         maybe_python_tool: maybe_python_proof.map(|_| python_choice.tool),
+        python_runner: python_runner_choice.tool,
+        maybe_python_typecheck_tool: maybe_python_proof
+            .and(maybe_python_typecheck_choice)
+            .map(|choice| choice.tool),
         maybe_java_kotlin_tool: maybe_java_kotlin_proof.map(|_| java_kotlin_choice.tool),
-        go_enabled: maybe_go_proof.is_some(),
+        maybe_go_tool: maybe_go_proof.map(|_| go_choice.tool),
+        go_vet_enabled: false,
         shell_enabled: maybe_shell_proof.is_some(),
-        terraform_enabled: maybe_terraform_proof.is_some(),
+        maybe_terraform_tool: maybe_terraform_proof.map(|_| terraform_choice.tool),
+        terraform_validate_enabled: false,
+        tflint_enabled: maybe_terraform_proof.is_some() && maybe_tflint_proof.is_some(),
         c_cpp_enabled: maybe_c_cpp_proof.is_some(),
-        ruby_enabled: maybe_ruby_proof.is_some(),
-        maybe_cargo_manifest_dir: maybe_cargo_dir,
+        clang_tidy_enabled: maybe_c_cpp_proof.is_some() && maybe_clang_tidy_proof.is_some(),
+        maybe_cmake_tool: maybe_c_cpp_proof
+            .and(maybe_cmake_proof)
+            .map(|_| cmake_choice.tool),
+        c_cpp_excluded_dirs: DEFAULT_C_CPP_EXCLUDED_DIRS
+            .iter()
+            .map(|dir| dir.to_string())
+            .collect(),
+        maybe_ruby_tool: maybe_ruby_proof.map(|_| ruby_choice.tool),
+        ruby_unsafe_autocorrect_enabled: true,
+        maybe_swift_tool: maybe_swift_proof.map(|_| swift_choice.tool),
+        maybe_php_tool: maybe_php_proof.map(|_| php_choice.tool),
+        csharp_enabled: maybe_csharp_proof.is_some(),
+        dart_enabled: maybe_dart_proof.is_some(),
+        elixir_enabled: maybe_elixir_proof.is_some(),
+        zig_enabled: maybe_zig_proof.is_some(),
+        dockerfile_lint_enabled: maybe_dockerfile_proof.is_some(),
+        github_actions_lint_enabled: maybe_github_actions_proof.is_some(),
+        maybe_kubernetes_tool: maybe_kubernetes_proof.map(|_| kubernetes_choice.tool),
+        secrets_scan_enabled: true,
+        hygiene: HygieneChecks::default(),
+        native_runner_enabled: true,
+        cargo_manifest_dirs: cargo_dirs,
+        parallel_stages_enabled: true,
+        timing_summary_enabled: true,
+        maybe_slow_stage_warn_seconds: None,
+        maybe_stage_timeout_seconds: None,
+        stage_timeout_policy: StageTimeoutPolicy::Fail,
+        stage_timeout_overrides: default_stage_timeout_overrides(
+            maybe_js_ts_proof.is_some(),
+            maybe_java_kotlin_proof.is_some(),
+            maybe_kubernetes_proof.is_some(),
+        ),
     })
 }
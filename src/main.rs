@@ -14,6 +14,7 @@ mod cargo_repo;
 mod cli;
 mod git_repo;
 mod hooks;
+mod hygiene;
 mod installer;
 mod status;
 mod util;
@@ -22,6 +23,7 @@ use crate::cargo_repo::ResolveHookOptions;
 use crate::cli::{Cli, Command, HookKind};
 use crate::git_repo::{find_git_repo, find_git_repos_under_dir};
 use crate::hooks::InstallOptions;
+use crate::hygiene::run_pre_commit_hygiene;
 use crate::installer::{
     disable_managed_pre_commit, install_resolved_hook, resolve_hook_kind,
     uninstall_managed_pre_commit,
@@ -33,17 +35,12 @@ fn install_in_repo(
     repo_root: &Path,
     git_dir: &Path,
     hook: Option<HookKind>,
-    manifest_dir: Option<PathBuf>,
+    manifest_dirs: &[PathBuf],
     resolve_options: ResolveHookOptions,
     install_options: InstallOptions,
 ) -> Result<()> {
-    let maybe_resolved_hook = resolve_hook_kind(
-        hook,
-        manifest_dir.as_deref(),
-        cwd,
-        repo_root,
-        resolve_options,
-    )?;
+    let maybe_resolved_hook =
+        resolve_hook_kind(hook, manifest_dirs, cwd, repo_root, resolve_options)?;
 
     let Some(resolved_hook) = maybe_resolved_hook else {
         println!("No hook selected.");
@@ -59,7 +56,7 @@ fn main() -> Result<()> {
     let cwd = env::current_dir().context("Failed to read current working directory")?;
     let command = cli.command.unwrap_or(Command::Install {
         hook: None,
-        manifest_dir: None,
+        manifest_dirs: Vec::new(),
     });
 
     match command {
@@ -70,7 +67,7 @@ fn main() -> Result<()> {
         }
         Command::InstallRecursive {
             hook,
-            manifest_dir,
+            manifest_dirs,
             max_depth,
             dir,
         } => {
@@ -127,7 +124,7 @@ fn main() -> Result<()> {
                     &repo_root,
                     &git_dir,
                     hook,
-                    manifest_dir.clone(),
+                    &manifest_dirs,
                     // After the global confirmation, don't ask the per-repo "install?" prompt.
                     ResolveHookOptions {
                         yes: true,
@@ -157,6 +154,7 @@ fn main() -> Result<()> {
         Command::Disable
         | Command::Uninstall
         | Command::Status { .. }
+        | Command::Run { .. }
         | Command::Install { .. } => {
             let (repo_root, git_dir) = match find_git_repo(&cwd)? {
                 Some(value) => value,
@@ -170,12 +168,19 @@ fn main() -> Result<()> {
                 Command::Disable => disable_managed_pre_commit(&git_dir),
                 Command::Uninstall => uninstall_managed_pre_commit(&git_dir),
                 Command::Status { verbose } => print_status(&repo_root, &git_dir, verbose),
-                Command::Install { hook, manifest_dir } => install_in_repo(
+                Command::Run {
+                    hook: HookKind::PreCommit,
+                    hygiene,
+                } => run_pre_commit_hygiene(&repo_root, hygiene.checks()),
+                Command::Install {
+                    hook,
+                    manifest_dirs,
+                } => install_in_repo(
                     &cwd,
                     &repo_root,
                     &git_dir,
                     hook,
-                    manifest_dir,
+                    &manifest_dirs,
                     ResolveHookOptions {
                         yes: cli.yes,
                         non_interactive: cli.non_interactive,
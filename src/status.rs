@@ -54,10 +54,15 @@ fn inspect_pre_commit(hooks_dir: &Path, verbose: bool) -> Result<()> {
         .any(|line| line.trim() == MANAGED_BLOCK_BEGIN);
     println!("pre-commit has git-hook-installer managed block: {has_managed_block}");
 
-    let looks_like_cargo_fmt = contents.lines().any(|line| line.trim() == "cargo fmt");
+    let looks_like_cargo_fmt = contents.lines().any(|line| {
+        let line = line.trim();
+        !line.starts_with('#') && line.contains("cargo fmt")
+    });
     println!("pre-commit runs cargo fmt: {looks_like_cargo_fmt}");
 
-    if let Some(cd_dir) = parse_cd_dir(&contents) {
+    if let Some(cargo_manifest_dirs) = parse_setting(&contents, "cargo_manifest_dirs") {
+        println!("pre-commit cargo manifest dirs: {cargo_manifest_dirs}");
+    } else if let Some(cd_dir) = parse_cd_dir(&contents) {
         println!("pre-commit cd: {cd_dir}");
     }
 
@@ -71,6 +76,15 @@ fn inspect_pre_commit(hooks_dir: &Path, verbose: bool) -> Result<()> {
     Ok(())
 }
 
+/// Reads a `#   key=value` line from the managed block's settings header.
+fn parse_setting<'a>(contents: &'a str, key: &str) -> Option<&'a str> {
+    contents.lines().find_map(|line| {
+        let rest = line.strip_prefix('#')?.trim_start();
+        let value = rest.strip_prefix(key)?.strip_prefix('=')?;
+        Some(value.trim())
+    })
+}
+
 fn parse_cd_dir(contents: &str) -> Option<String> {
     for line in contents.lines() {
         let line = line.trim();